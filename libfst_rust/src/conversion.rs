@@ -0,0 +1,234 @@
+//! Typed interpretation of a variable's raw 4-state value, keyed on its
+//! `VarType` (with a user override). Lets GUI/column code ask for
+//! decimal/float/bool/time rendering uniformly instead of re-parsing the
+//! bit string at every call site.
+
+use std::str::FromStr;
+
+use crate::hierarchy::{Timescale, Var, VarType};
+
+/// How to interpret a variable's raw value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conversion {
+    /// As-is: the raw 4-state bit string (or text, for string/real vars).
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    /// Tick count scaled by the hierarchy's `Timescale`, formatted with the
+    /// default `"%Y-%m-%d %H:%M:%S"` layout.
+    Timestamp,
+    /// Like `Timestamp`, with a caller-supplied strftime-style format.
+    TimestampFmt(String),
+    /// Like `TimestampFmt`, rendered in UTC explicitly (no local timezone
+    /// conversion is available in this crate, so this behaves the same as
+    /// `TimestampFmt` today).
+    TimestampTZFmt(String),
+}
+
+impl FromStr for Conversion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "int" | "integer" => Ok(Conversion::Integer),
+            "float" => Ok(Conversion::Float),
+            "bool" | "boolean" => Ok(Conversion::Boolean),
+            "string" | "asis" | "bytes" => Ok(Conversion::Bytes),
+            other => Err(format!("Unknown conversion: {}", other)),
+        }
+    }
+}
+
+/// Result of applying a `Conversion` to one raw value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvertedValue {
+    Bytes(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Timestamp(String),
+    /// The raw value has an `x`/`z` bit and can't be meaningfully converted
+    /// to the target type.
+    Indeterminate,
+}
+
+impl Var {
+    /// A sensible default `Conversion` for this variable's `VarType`:
+    /// `Integer` for the HDL integer-ish types, `Float` for real-valued
+    /// vars, `Bytes` for strings, and `Bytes` (as-is) otherwise.
+    pub fn default_conversion(&self) -> Conversion {
+        if self.is_real() {
+            return Conversion::Float;
+        }
+        if self.is_string() {
+            return Conversion::Bytes;
+        }
+        match self.var_type {
+            VarType::Int
+            | VarType::ShortInt
+            | VarType::LongInt
+            | VarType::Byte
+            | VarType::Integer
+            | VarType::Reg
+            | VarType::Wire => Conversion::Integer,
+            _ => Conversion::Bytes,
+        }
+    }
+}
+
+impl Conversion {
+    /// Parse a raw 4-state bit string (e.g. `b"1010"`, one byte per bit) or
+    /// raw text (for `Bytes`/`Float` over string/real vars) into a typed
+    /// `ConvertedValue`. Any `x`/`z` bit makes every target but `Bytes`
+    /// resolve to `Indeterminate`.
+    pub fn apply(&self, raw: &[u8]) -> ConvertedValue {
+        self.apply_scaled(raw, None)
+    }
+
+    /// Like `apply`, but `Timestamp`/`TimestampFmt`/`TimestampTZFmt` scale
+    /// the raw tick count by `timescale` before formatting (ticks are used
+    /// as-is when `None`).
+    pub fn apply_scaled(&self, raw: &[u8], timescale: Option<&Timescale>) -> ConvertedValue {
+        let text = String::from_utf8_lossy(raw);
+
+        if matches!(self, Conversion::Bytes) {
+            return ConvertedValue::Bytes(text.into_owned());
+        }
+
+        let has_xz = raw.iter().any(|&b| matches!(b, b'x' | b'X' | b'z' | b'Z'));
+
+        match self {
+            Conversion::Bytes => unreachable!("handled above"),
+            Conversion::Float => match text.parse::<f64>() {
+                Ok(v) => ConvertedValue::Float(v),
+                Err(_) if has_xz => ConvertedValue::Indeterminate,
+                Err(_) => bits_to_u64(raw)
+                    .map(|v| ConvertedValue::Float(v as f64))
+                    .unwrap_or(ConvertedValue::Indeterminate),
+            },
+            Conversion::Integer => {
+                if has_xz {
+                    return ConvertedValue::Indeterminate;
+                }
+                bits_to_u64(raw)
+                    .map(|v| ConvertedValue::Integer(v as i64))
+                    .unwrap_or(ConvertedValue::Indeterminate)
+            }
+            Conversion::Boolean => {
+                if has_xz {
+                    return ConvertedValue::Indeterminate;
+                }
+                bits_to_u64(raw)
+                    .map(|v| ConvertedValue::Boolean(v != 0))
+                    .unwrap_or(ConvertedValue::Indeterminate)
+            }
+            Conversion::Timestamp => {
+                if has_xz {
+                    return ConvertedValue::Indeterminate;
+                }
+                bits_to_u64(raw)
+                    .map(|ticks| ConvertedValue::Timestamp(format_timestamp(ticks, timescale, "%Y-%m-%d %H:%M:%S")))
+                    .unwrap_or(ConvertedValue::Indeterminate)
+            }
+            Conversion::TimestampFmt(fmt) | Conversion::TimestampTZFmt(fmt) => {
+                if has_xz {
+                    return ConvertedValue::Indeterminate;
+                }
+                bits_to_u64(raw)
+                    .map(|ticks| ConvertedValue::Timestamp(format_timestamp(ticks, timescale, fmt)))
+                    .unwrap_or(ConvertedValue::Indeterminate)
+            }
+        }
+    }
+}
+
+/// Parse a 4-state bit string (MSB first) into an unsigned integer, the
+/// same convention `PackedBits`/`SignalValue` use elsewhere.
+fn bits_to_u64(raw: &[u8]) -> Option<u64> {
+    if raw.len() > 64 {
+        return None;
+    }
+    let mut val = 0u64;
+    for &b in raw {
+        val <<= 1;
+        match b {
+            b'1' => val |= 1,
+            b'0' => {}
+            _ => return None,
+        }
+    }
+    Some(val)
+}
+
+/// Scale `ticks` to whole seconds using `timescale`'s `factor * 10^exponent`
+/// (e.g. a `10 ns` timescale multiplies by `10 * 10^-9`); ticks are treated
+/// as already being seconds when no timescale is known.
+fn scale_to_seconds(ticks: u64, timescale: Option<&Timescale>) -> i64 {
+    let Some(ts) = timescale else { return ticks as i64 };
+    let Some(exponent) = ts.unit.to_exponent() else { return ticks as i64 };
+    let scaled = ticks.saturating_mul(ts.factor as u64);
+
+    if exponent >= 0 {
+        scaled.saturating_mul(10u64.saturating_pow(exponent as u32)) as i64
+    } else {
+        let divisor = 10u64.saturating_pow((-exponent) as u32).max(1);
+        (scaled / divisor) as i64
+    }
+}
+
+/// Render a Unix-epoch second count via a small strftime-style subset
+/// (`%Y %m %d %H %M %S`); this crate has no date-formatting dependency, so
+/// only the placeholders timestamp columns actually need are supported.
+fn format_timestamp(ticks: u64, timescale: Option<&Timescale>, fmt: &str) -> String {
+    let seconds = scale_to_seconds(ticks, timescale);
+    let (year, month, day, hour, minute, second) = civil_from_unix(seconds);
+
+    let mut out = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => out.push_str(&format!("{:04}", year)),
+            Some('m') => out.push_str(&format!("{:02}", month)),
+            Some('d') => out.push_str(&format!("{:02}", day)),
+            Some('H') => out.push_str(&format!("{:02}", hour)),
+            Some('M') => out.push_str(&format!("{:02}", minute)),
+            Some('S') => out.push_str(&format!("{:02}", second)),
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+/// Civil (Gregorian) date/time from a Unix-epoch second count, using
+/// Howard Hinnant's `civil_from_days` algorithm (proleptic Gregorian,
+/// valid for the full `i64` range).
+fn civil_from_unix(total_seconds: i64) -> (i64, u32, u32, u32, u32, u32) {
+    let days = total_seconds.div_euclid(86_400);
+    let secs_of_day = total_seconds.rem_euclid(86_400);
+    let hour = (secs_of_day / 3600) as u32;
+    let minute = ((secs_of_day % 3600) / 60) as u32;
+    let second = (secs_of_day % 60) as u32;
+
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let year = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { year + 1 } else { year };
+
+    (year, month, day, hour, minute, second)
+}
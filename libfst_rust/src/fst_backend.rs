@@ -0,0 +1,163 @@
+use std::sync::{Arc, Mutex};
+
+use crate::backend::SignalBackend;
+use crate::ffi::{FstHandle, FstReader};
+use crate::hierarchy::Hierarchy;
+use crate::signal::{Signal, SignalValue, TimeTable};
+
+// C callback context for signal loading
+struct SignalLoadContext {
+    signal: Arc<Mutex<Signal>>,
+    is_real: bool,
+    is_string: bool,
+    // Transitions outside this range are dropped instead of appended, so a
+    // windowed load never grows `signal.changes` past what the window needs.
+    window: (u64, u64),
+}
+
+// C callback function for FST value changes
+unsafe extern "C" fn signal_callback(
+    user_data: *mut std::os::raw::c_void,
+    time: u64,
+    _handle: FstHandle,
+    value: *const u8, // const unsigned char *
+) {
+    let ctx = &*(user_data as *const SignalLoadContext);
+
+    let (window_start, window_end) = ctx.window;
+    if time < window_start || time > window_end {
+        return;
+    }
+
+    // Convert value to string - FST uses null-terminated strings
+    let value_str = if value.is_null() {
+        String::new()
+    } else {
+        // Find the null terminator
+        let mut len = 0;
+        while *value.add(len) != 0 {
+            len += 1;
+        }
+
+        // Convert to string
+        let slice = std::slice::from_raw_parts(value, len);
+        String::from_utf8_lossy(slice).to_string()
+    };
+
+    let signal_value = SignalValue::from_fst_string(&value_str, ctx.is_real, ctx.is_string);
+
+    let mut signal = ctx.signal.lock().unwrap();
+    signal.add_change(time, signal_value);
+}
+
+/// Load a single signal's transitions from an open FST reader.
+pub fn load_signal_from_fst(
+    reader: &FstReader,
+    handle: FstHandle,
+    is_real: bool,
+    is_string: bool,
+    time_table: Arc<TimeTable>,
+) -> Result<Signal, String> {
+    load_signal_window_from_fst(reader, handle, is_real, is_string, time_table, (0, u64::MAX))
+}
+
+/// Load only the transitions of one signal within `[start_time, end_time]`.
+///
+/// Uses the same block process mask trick as a full load to restrict
+/// decoding to this one handle, plus a time check in [`signal_callback`] so
+/// the resulting `Signal` never holds more than the requested window.
+///
+/// Note this does *not* skip I/O or decoding for blocks outside the window -
+/// `iterate_blocks` still walks every block in the file; only the memory
+/// cost of the result is bounded. Restricting the walk itself would need a
+/// block-level time range check (e.g. per-block min/max time) before a
+/// block is decoded at all, which the current reader doesn't expose.
+pub fn load_signal_window_from_fst(
+    reader: &FstReader,
+    handle: FstHandle,
+    is_real: bool,
+    is_string: bool,
+    time_table: Arc<TimeTable>,
+    window: (u64, u64),
+) -> Result<Signal, String> {
+    let signal = Arc::new(Mutex::new(Signal::new(time_table)));
+
+    // Create context without cloning the Arc
+    {
+        let ctx = SignalLoadContext {
+            signal: signal.clone(),
+            is_real,
+            is_string,
+            window,
+        };
+
+        // Clear all masks and set only the one we want
+        reader.clear_fac_process_mask_all();
+        reader.set_fac_process_mask(handle);
+
+        // Load signal data
+        let ctx_ptr = &ctx as *const _ as *mut std::os::raw::c_void;
+        if !reader.iterate_blocks(Some(signal_callback), ctx_ptr) {
+            return Err("Failed to iterate blocks".to_string());
+        }
+    } // ctx is dropped here, releasing the clone
+
+    // Now we can extract signal from Arc<Mutex>
+    let signal = Arc::try_unwrap(signal)
+        .map_err(|_| "Failed to unwrap signal Arc")?
+        .into_inner()
+        .map_err(|_| "Failed to unwrap signal Mutex")?;
+
+    Ok(signal)
+}
+
+/// `SignalBackend` implementation backed by the `libfst` C reader.
+pub struct FstBackend {
+    reader: Arc<FstReader>,
+    time_table: Arc<TimeTable>,
+}
+
+impl FstBackend {
+    pub fn open(path: &str) -> Result<Self, String> {
+        Ok(FstBackend {
+            reader: Arc::new(FstReader::open(path)?),
+            time_table: Arc::new(TimeTable::new()),
+        })
+    }
+}
+
+impl SignalBackend for FstBackend {
+    fn hierarchy(&self) -> Result<Hierarchy, String> {
+        Hierarchy::from_fst(&self.reader)
+    }
+
+    fn load_signal(&self, handle: FstHandle, is_real: bool, is_string: bool) -> Result<Signal, String> {
+        load_signal_from_fst(&self.reader, handle, is_real, is_string, self.time_table.clone())
+    }
+
+    fn load_signal_window(
+        &self,
+        handle: FstHandle,
+        is_real: bool,
+        is_string: bool,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Signal, String> {
+        load_signal_window_from_fst(
+            &self.reader,
+            handle,
+            is_real,
+            is_string,
+            self.time_table.clone(),
+            (start_time, end_time),
+        )
+    }
+
+    fn time_range(&self) -> (u64, u64) {
+        (self.reader.start_time(), self.reader.end_time())
+    }
+
+    fn time_table(&self) -> Arc<TimeTable> {
+        self.time_table.clone()
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use crate::ffi::{
     self, FstHandle, FstReader, FST_HT_SCOPE, FST_HT_UPSCOPE, FST_HT_VAR,
     FST_HT_ATTRBEGIN, FST_HT_ATTREND, FST_HT_TREEBEGIN, FST_HT_TREEEND,
+    FST_AT_ENUM, FST_AT_MISC, FST_MT_SOURCEISTEM, FST_MT_SOURCESTEM,
     FST_ST_VCD_BEGIN, FST_ST_VCD_FORK, FST_ST_VCD_FUNCTION, FST_ST_VCD_GENERATE,
     FST_ST_VCD_MODULE, FST_ST_VCD_TASK, FST_VD_IMPLICIT, FST_VD_INOUT, FST_VD_INPUT,
     FST_VD_OUTPUT, FST_VT_VCD_EVENT, FST_VT_VCD_INTEGER, FST_VT_VCD_PARAMETER,
@@ -26,16 +27,28 @@ pub struct ScopeRef(pub usize);
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct VarRef(pub usize);
 
-/// Variable index for bit ranges
+/// Variable index for one dimension of a bit range or array.
+///
+/// `msb`/`lsb` are always normalized so `msb >= lsb`; `descending` records
+/// whether the source declared the range high-to-low (Verilog `[msb:lsb]`,
+/// VHDL `downto`) or low-to-high (VHDL `to`), which `msb`/`lsb` alone can't
+/// tell apart once normalized.
 #[derive(Debug, Clone)]
 pub struct VarIndex {
     pub msb: i32,
     pub lsb: i32,
+    pub descending: bool,
 }
 
 impl VarIndex {
     pub fn new(msb: i32, lsb: i32) -> Self {
-        VarIndex { msb, lsb }
+        let descending = msb >= lsb;
+        let (msb, lsb) = if descending { (msb, lsb) } else { (lsb, msb) };
+        VarIndex { msb, lsb, descending }
+    }
+
+    fn with_direction(msb: i32, lsb: i32, descending: bool) -> Self {
+        VarIndex { msb, lsb, descending }
     }
 }
 
@@ -222,13 +235,24 @@ pub struct Var {
     pub direction: VarDirection,
     pub length: Option<u32>,
     pub signal_ref: SignalRef,
-    pub index: Option<VarIndex>,
+    /// Bit-range/array dimensions peeled off the name, innermost first (so
+    /// for a plain `data[7:0]` this holds exactly that one range). VHDL
+    /// multi-dimensional names like `mem[3][7:0]` collect one entry per
+    /// trailing group, outer dimensions later in the vec.
+    pub indices: Vec<VarIndex>,
     pub scope: Option<ScopeRef>,
     pub fst_handle: FstHandle,
+    /// Enum value->label table from a preceding `FST_AT_ENUM` attribute
+    /// record, if any. `Arc`-shared since many vars of the same enum type
+    /// reuse the same table.
+    pub enum_map: Option<Arc<Vec<(String, String)>>>,
+    /// Source file/line from a preceding `FST_MT_SOURCESTEM`/
+    /// `FST_MT_SOURCEISTEM` attribute record, if any.
+    pub source: Option<(String, u32)>,
 }
 
 impl Var {
-    fn new(
+    pub(crate) fn new(
         name: String,
         var_type: VarType,
         direction: VarDirection,
@@ -236,30 +260,47 @@ impl Var {
         signal_ref: SignalRef,
         fst_handle: FstHandle,
         scope: Option<ScopeRef>,
+    ) -> Self {
+        Self::with_attrs(name, var_type, direction, length, signal_ref, fst_handle, scope, None, None)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn with_attrs(
+        name: String,
+        var_type: VarType,
+        direction: VarDirection,
+        length: Option<u32>,
+        signal_ref: SignalRef,
+        fst_handle: FstHandle,
+        scope: Option<ScopeRef>,
+        enum_map: Option<Arc<Vec<(String, String)>>>,
+        source: Option<(String, u32)>,
     ) -> Self {
         // Parse bit range from name if present
-        let (clean_name, index) = parse_bit_range(&name);
-        
+        let (clean_name, indices) = parse_bit_range(&name);
+
         Var {
             name: clean_name,
             var_type,
             direction,
             length,
             signal_ref,
-            index,
+            indices,
             scope,
             fst_handle,
+            enum_map,
+            source,
         }
     }
-    
+
     pub fn is_real(&self) -> bool {
         self.var_type.is_real()
     }
-    
+
     pub fn is_string(&self) -> bool {
         self.var_type.is_string()
     }
-    
+
     pub fn is_1bit(&self) -> bool {
         // Strings and real values are never 1-bit wires
         if self.is_string() || self.is_real() {
@@ -267,39 +308,129 @@ impl Var {
         }
         self.length.unwrap_or(1) == 1
     }
-    
+
     pub fn bitwidth(&self) -> Option<u32> {
         self.length
     }
+
+    /// The innermost (rightmost-declared) bit range, e.g. the `[7:0]` of
+    /// `data[7:0]` or of `mem[3][7:0]`. Kept for callers that only care
+    /// about a single range and predate multi-dimensional `indices`.
+    pub fn index(&self) -> Option<&VarIndex> {
+        self.indices.first()
+    }
+
+    /// Resolve `raw_value` (a bit string, e.g. `"011"`) to its enum label,
+    /// if this var has an `enum_map` and `raw_value` is one of its entries.
+    pub fn enum_label(&self, raw_value: &str) -> Option<&str> {
+        self.enum_map
+            .as_ref()?
+            .iter()
+            .find(|(value, _)| value == raw_value)
+            .map(|(_, label)| label.as_str())
+    }
 }
 
-/// Parse bit range from signal name
-fn parse_bit_range(name: &str) -> (String, Option<VarIndex>) {
-    if let Some(idx) = name.rfind('[') {
-        if let Some(end_idx) = name.rfind(']') {
-            if end_idx > idx {
-                let base = name[..idx].to_string();
-                let range = &name[idx + 1..end_idx];
-                
-                // Parse range like "7:0" or "15:8"
-                if let Some(colon_idx) = range.find(':') {
-                    let msb_str = &range[..colon_idx];
-                    let lsb_str = &range[colon_idx + 1..];
-                    
-                    if let (Ok(msb), Ok(lsb)) = (msb_str.parse::<i32>(), lsb_str.parse::<i32>()) {
-                        return (base, Some(VarIndex::new(msb, lsb)));
-                    }
-                }
-                
-                // Parse single index like "[0]"
-                if let Ok(idx) = range.parse::<i32>() {
-                    return (base, Some(VarIndex::new(idx, idx)));
-                }
-            }
-        }
+/// Parse an `FST_AT_ENUM` attribute's name text into a value->label table.
+///
+/// The table is encoded as a single space-separated string:
+/// `<enum_name> <count> <val0> .. <valN-1> <label0> .. <labelN-1>`, where
+/// each `valN` is a 4-state bit string (one character per bit, matching the
+/// same convention `PackedBits`/`SignalValue` use elsewhere).
+fn parse_enum_table(text: &str) -> Option<Vec<(String, String)>> {
+    let mut parts = text.split(' ');
+    let _enum_name = parts.next()?;
+    let count: usize = parts.next()?.parse().ok()?;
+
+    let rest: Vec<&str> = parts.collect();
+    if rest.len() < count * 2 {
+        return None;
+    }
+
+    let values = &rest[..count];
+    let labels = &rest[count..count * 2];
+    Some(
+        values
+            .iter()
+            .zip(labels.iter())
+            .map(|(v, l)| (v.to_string(), l.to_string()))
+            .collect(),
+    )
+}
+
+/// Parse bit range(s) from a signal name, peeling any number of trailing
+/// Verilog `[msb:lsb]`/`[idx]` groups and VHDL `(hi downto lo)`/`(lo to hi)`
+/// ranges off the name, innermost group first.
+///
+/// VCD's `$var` grammar allows the bit-range to trail the name as a
+/// separate, space-separated token (e.g. `data [7:0]`), so the base name is
+/// trimmed after each group is peeled - otherwise a VCD-sourced `Var` would
+/// keep a trailing space that an FST-sourced one never has, breaking
+/// `path_to_var` lookups by path.
+fn parse_bit_range(name: &str) -> (String, Vec<VarIndex>) {
+    let mut indices = Vec::new();
+    let mut base = name.to_string();
+
+    while let Some((next_base, var_index)) = peel_trailing_group(&base) {
+        indices.push(var_index);
+        base = next_base;
+    }
+
+    (base, indices)
+}
+
+/// Peel one trailing `[...]` or `(...)` group off `name`, returning the
+/// remaining base name and the parsed range. Returns `None` once there's no
+/// more trailing group, or the trailing group isn't a range this parser
+/// understands (e.g. a literal parenthesized comment).
+fn peel_trailing_group(name: &str) -> Option<(String, VarIndex)> {
+    let trimmed = name.trim_end();
+
+    if let Some(rest) = trimmed.strip_suffix(']') {
+        let open = rest.rfind('[')?;
+        let range = &rest[open + 1..];
+        let var_index = parse_verilog_range(range)?;
+        return Some((rest[..open].trim_end().to_string(), var_index));
+    }
+
+    if let Some(rest) = trimmed.strip_suffix(')') {
+        let open = rest.rfind('(')?;
+        let range = &rest[open + 1..];
+        let var_index = parse_vhdl_range(range)?;
+        return Some((rest[..open].trim_end().to_string(), var_index));
+    }
+
+    None
+}
+
+/// Parse a Verilog-style range body: `"7:0"` (a `[msb:lsb]` slice) or `"3"`
+/// (a single `[idx]` index).
+fn parse_verilog_range(range: &str) -> Option<VarIndex> {
+    if let Some(colon_idx) = range.find(':') {
+        let msb = range[..colon_idx].trim().parse().ok()?;
+        let lsb = range[colon_idx + 1..].trim().parse().ok()?;
+        return Some(VarIndex::new(msb, lsb));
+    }
+    let idx = range.trim().parse().ok()?;
+    Some(VarIndex::new(idx, idx))
+}
+
+/// Parse a VHDL-style range body: `"7 downto 0"` (descending) or
+/// `"0 to 7"` (ascending). `msb`/`lsb` are normalized high/low regardless of
+/// direction; `descending` records which form was written.
+fn parse_vhdl_range(range: &str) -> Option<VarIndex> {
+    let tokens: Vec<&str> = range.split_whitespace().collect();
+    let [first, keyword, second] = tokens[..] else {
+        return None;
+    };
+    let first: i32 = first.parse().ok()?;
+    let second: i32 = second.parse().ok()?;
+
+    match keyword {
+        "downto" => Some(VarIndex::with_direction(first, second, true)),
+        "to" => Some(VarIndex::with_direction(second, first, false)),
+        _ => None,
     }
-    
-    (name.to_string(), None)
 }
 
 /// Timescale unit
@@ -367,6 +498,7 @@ impl Timescale {
 }
 
 /// Main hierarchy structure
+#[derive(Clone)]
 pub struct Hierarchy {
     pub scopes: Vec<Scope>,
     pub vars: Vec<Var>,
@@ -402,6 +534,12 @@ impl Hierarchy {
         let mut scope_stack: Vec<ScopeRef> = Vec::new();
         let mut current_path = Vec::new();
         let mut signal_counter = 0usize;
+
+        // Attributes precede the var (or scope) they annotate, so stash them
+        // here on `FST_HT_ATTRBEGIN` and apply/clear on the next `FST_HT_VAR`
+        // (or `FST_HT_ATTREND`, whichever comes first).
+        let mut pending_enum_map: Option<Arc<Vec<(String, String)>>> = None;
+        let mut pending_source: Option<(String, u32)> = None;
         
         // Rewind to start
         reader.rewind_hier();
@@ -472,8 +610,9 @@ impl Hierarchy {
                     let scope = scope_stack.last().copied();
                     let var_ref = VarRef(hierarchy.vars.len());
                     
-                    // Create the variable (which will parse and clean the name)
-                    let var = Var::new(
+                    // Create the variable (which will parse and clean the name),
+                    // consuming any attribute(s) that preceded it.
+                    let var = Var::with_attrs(
                         name.clone(),
                         var_type,
                         direction,
@@ -481,6 +620,8 @@ impl Hierarchy {
                         signal_ref,
                         var_data.handle,
                         scope,
+                        pending_enum_map.take(),
+                        pending_source.take(),
                     );
                     
                     // Build full path for lookup using the cleaned name
@@ -498,11 +639,29 @@ impl Hierarchy {
                 }
                 
                 FST_HT_ATTRBEGIN => {
-                    // Skip attributes for now
+                    let attr = unsafe { hier.u.attr };
+                    if attr.name.is_null() {
+                        continue;
+                    }
+                    let text = unsafe { ffi::c_str_to_string(attr.name, attr.name_length) };
+
+                    if attr.typ == FST_AT_ENUM {
+                        if let Some(table) = parse_enum_table(&text) {
+                            pending_enum_map = Some(Arc::new(table));
+                        }
+                    } else if attr.typ == FST_AT_MISC
+                        && matches!(attr.subtype, FST_MT_SOURCESTEM | FST_MT_SOURCEISTEM)
+                    {
+                        pending_source = Some((text, attr.arg as u32));
+                    }
                 }
-                
+
                 FST_HT_ATTREND => {
-                    // Skip attributes for now
+                    // An attribute that wasn't followed by a var (e.g. one
+                    // attached to a scope) never gets consumed - drop it here
+                    // so it doesn't leak onto some unrelated later var.
+                    pending_enum_map = None;
+                    pending_source = None;
                 }
                 
                 FST_HT_TREEBEGIN => {
@@ -559,9 +718,9 @@ impl Hierarchy {
             path.reverse();
         }
         
-        // Add variable name (without bit range - it's stored separately in index)
+        // Add variable name (without bit range - it's stored separately in indices)
         path.push(var.name.clone());
-        
+
         // Don't add bit range to match pywellen behavior
         path.join(".")
     }
@@ -1,16 +1,150 @@
-use std::collections::BTreeMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
 
-use crate::ffi::{FstHandle, FstReader, FstValueChangeCb};
+use crate::backend::SignalBackend;
+use crate::ffi::FstHandle;
 use crate::hierarchy::SignalRef;
 
+/// One bit sample of a 4-state signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitState {
+    Zero,
+    One,
+    X,
+    Z,
+}
+
+impl BitState {
+    fn code(self) -> u64 {
+        match self {
+            BitState::Zero => 0,
+            BitState::One => 1,
+            BitState::X => 2,
+            BitState::Z => 3,
+        }
+    }
+
+    fn from_code(code: u64) -> Self {
+        match code & 0b11 {
+            0 => BitState::Zero,
+            1 => BitState::One,
+            2 => BitState::X,
+            _ => BitState::Z,
+        }
+    }
+
+    fn from_char(c: char) -> Self {
+        match c {
+            '1' => BitState::One,
+            'x' | 'X' => BitState::X,
+            'z' | 'Z' => BitState::Z,
+            _ => BitState::Zero,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            BitState::Zero => '0',
+            BitState::One => '1',
+            BitState::X => 'x',
+            BitState::Z => 'z',
+        }
+    }
+}
+
+/// A 4-state bit vector packed two bits per sample (00/01/10/11 for 0/1/x/z)
+/// instead of one byte per bit or per character. Sample 0 is the MSB.
+/// `has_xz` is set once at construction so `to_int` can take the
+/// branch-light all-binary path instead of checking each bit for x/z.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedBits {
+    width: u32,
+    words: Vec<u64>,
+    has_xz: bool,
+}
+
+impl PackedBits {
+    /// Pack a 4-state bit string (e.g. from an FST/VCD value change) where
+    /// index 0 is the MSB.
+    pub fn from_bits(bits: &str) -> Self {
+        let width = bits.chars().count() as u32;
+        let word_count = ((width as usize) * 2).div_ceil(64).max(1);
+        let mut words = vec![0u64; word_count];
+        let mut has_xz = false;
+
+        for (i, c) in bits.chars().enumerate() {
+            let state = BitState::from_char(c);
+            if matches!(state, BitState::X | BitState::Z) {
+                has_xz = true;
+            }
+            let bitpos = i * 2;
+            words[bitpos / 64] |= state.code() << (bitpos % 64);
+        }
+
+        PackedBits { width, words, has_xz }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn has_xz(&self) -> bool {
+        self.has_xz
+    }
+
+    pub fn get(&self, index: u32) -> BitState {
+        let bitpos = (index as usize) * 2;
+        BitState::from_code(self.words[bitpos / 64] >> (bitpos % 64))
+    }
+
+    /// Convert to an unsigned integer, branch-light when no sample is x/z.
+    pub fn to_int(&self) -> Option<u64> {
+        if self.has_xz || self.width > 64 {
+            return None;
+        }
+        let mut val = 0u64;
+        for i in 0..self.width {
+            if self.get(i) == BitState::One {
+                val |= 1u64 << (self.width - 1 - i);
+            }
+        }
+        Some(val)
+    }
+
+    pub fn to_string_repr(&self) -> String {
+        (0..self.width).map(|i| self.get(i).to_char()).collect()
+    }
+}
+
+/// Search direction for edge/value queries on a `Signal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/// One point produced by [`Signal::decimate`]: a real value and the time it
+/// was observed at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecimatedPoint {
+    pub time: u64,
+    pub value: f64,
+}
+
+/// Bit 0 of `value` if it is a single-bit `Bits` value, else `None`.
+fn single_bit_state(value: &SignalValue) -> Option<BitState> {
+    match value {
+        SignalValue::Bits(bits) if bits.width() == 1 => Some(bits.get(0)),
+        _ => None,
+    }
+}
+
 /// Signal value enumeration
 #[derive(Debug, Clone, PartialEq)]
 pub enum SignalValue {
-    Binary(Vec<u8>),    // Binary values (0/1)
-    FourValue(String),  // Four-value logic (0/1/x/z)
-    Real(f64),          // Real numbers
-    String(String),     // String values
+    Bits(PackedBits),  // 4-state bit vector, packed two bits per sample
+    Real(f64),         // Real numbers
+    String(String),    // String values
 }
 
 impl SignalValue {
@@ -22,57 +156,96 @@ impl SignalValue {
                 return SignalValue::Real(val);
             }
         }
-        
+
         if is_string {
             return SignalValue::String(s.to_string());
         }
-        
-        // Check if it's pure binary
-        if s.chars().all(|c| c == '0' || c == '1') {
-            let bytes: Vec<u8> = s.chars().map(|c| if c == '1' { 1 } else { 0 }).collect();
-            SignalValue::Binary(bytes)
-        } else {
-            // Contains x/z/X/Z or other values - treat as four-value
-            SignalValue::FourValue(s.to_string())
-        }
+
+        SignalValue::Bits(PackedBits::from_bits(s))
     }
-    
+
     /// Convert to integer if possible
     pub fn to_int(&self) -> Option<u64> {
         match self {
-            SignalValue::Binary(bits) => {
-                if bits.len() > 64 {
-                    return None; // Too large for u64
-                }
-                let mut val = 0u64;
-                for (i, &bit) in bits.iter().rev().enumerate() {
-                    if bit == 1 {
-                        val |= 1u64 << i;
-                    }
-                }
-                Some(val)
-            }
+            SignalValue::Bits(bits) => bits.to_int(),
             _ => None,
         }
     }
-    
+
     /// Convert to string representation
     pub fn to_string_repr(&self) -> String {
         match self {
-            SignalValue::Binary(bits) => {
-                bits.iter().map(|&b| if b == 1 { '1' } else { '0' }).collect()
-            }
-            SignalValue::FourValue(s) => s.clone(),
+            SignalValue::Bits(bits) => bits.to_string_repr(),
             SignalValue::Real(r) => r.to_string(),
             SignalValue::String(s) => s.clone(),
         }
     }
 }
 
-/// Signal change (time, value) pair
+/// Time table for compressed time representation, shared by every signal
+/// loaded from the same file. Storing `time_index: u32` on each
+/// `SignalChange` instead of a full `u64` timestamp roughly halves
+/// per-change memory, and signals that share timestamps (the common case -
+/// most designs change many signals in the same cycle) share one entry
+/// instead of each repeating it.
+#[derive(Debug, Default)]
+pub struct TimeTable {
+    times: RwLock<Vec<u64>>,
+    index: Mutex<HashMap<u64, u32>>,
+}
+
+impl TimeTable {
+    pub fn new() -> Self {
+        TimeTable {
+            times: RwLock::new(Vec::new()),
+            index: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Find-or-insert `time`, returning its stable index.
+    pub fn intern(&self, time: u64) -> u32 {
+        if let Some(&idx) = self.index.lock().unwrap().get(&time) {
+            return idx;
+        }
+
+        let mut times = self.times.write().unwrap();
+        let mut index = self.index.lock().unwrap();
+        // Re-check: another thread may have interned `time` while we waited.
+        if let Some(&idx) = index.get(&time) {
+            return idx;
+        }
+
+        let idx = times.len() as u32;
+        times.push(time);
+        index.insert(time, idx);
+        idx
+    }
+
+    pub fn get(&self, idx: u32) -> Option<u64> {
+        self.times.read().unwrap().get(idx as usize).copied()
+    }
+
+    /// Lock once and hand back a read guard callers can index into
+    /// repeatedly (e.g. across every step of a binary search or scan)
+    /// instead of re-locking per lookup. Times are only ever appended, so
+    /// the guard stays valid for any index already handed out by `intern`.
+    pub fn read(&self) -> RwLockReadGuard<'_, Vec<u64>> {
+        self.times.read().unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.times.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Signal change (time index into the shared `TimeTable`, value) pair
 #[derive(Debug, Clone)]
 pub struct SignalChange {
-    pub time: u64,
+    pub time_index: u32,
     pub value: SignalValue,
 }
 
@@ -80,28 +253,49 @@ pub struct SignalChange {
 #[derive(Debug, Clone)]
 pub struct Signal {
     pub changes: Vec<SignalChange>,
+    time_table: Arc<TimeTable>,
 }
 
 impl Signal {
-    pub fn new() -> Self {
+    pub fn new(time_table: Arc<TimeTable>) -> Self {
         Signal {
             changes: Vec::new(),
+            time_table,
         }
     }
-    
-    /// Add a change to the signal
+
+    /// Add a change to the signal, interning `time` into the shared time table
     pub fn add_change(&mut self, time: u64, value: SignalValue) {
-        self.changes.push(SignalChange { time, value });
+        let time_index = self.time_table.intern(time);
+        self.changes.push(SignalChange { time_index, value });
     }
-    
+
+    /// Lock the shared time table once, for the duration of one call, so a
+    /// binary search or scan over `self.changes` resolves every timestamp
+    /// via a single read-lock acquisition instead of one per comparison.
+    /// Times are only ever appended after load, so holding this guard
+    /// across a whole search is always consistent with later inserts.
+    fn times(&self) -> RwLockReadGuard<'_, Vec<u64>> {
+        self.time_table.read()
+    }
+
+    fn resolve(times: &[u64], change: &SignalChange) -> u64 {
+        times.get(change.time_index as usize).copied().unwrap_or(0)
+    }
+
+    fn time_at(&self, idx: usize) -> u64 {
+        Self::resolve(&self.times(), &self.changes[idx])
+    }
+
     /// Get value at specific time using binary search
     pub fn value_at_time(&self, time: u64) -> Option<&SignalValue> {
         if self.changes.is_empty() {
             return None;
         }
-        
+
+        let times = self.times();
         // Binary search for the last change at or before the given time
-        let idx = match self.changes.binary_search_by_key(&time, |c| c.time) {
+        let idx = match self.changes.binary_search_by(|c| Self::resolve(&times, c).cmp(&time)) {
             Ok(idx) => idx,
             Err(idx) => {
                 if idx == 0 {
@@ -110,28 +304,225 @@ impl Signal {
                 idx - 1
             }
         };
-        
+
         Some(&self.changes[idx].value)
     }
-    
+
     /// Get value at specific index
     pub fn value_at_idx(&self, idx: usize) -> Option<&SignalValue> {
         self.changes.get(idx).map(|c| &c.value)
     }
-    
+
     /// Iterator over all signal transitions
     pub fn all_changes(&self) -> impl Iterator<Item = (u64, &SignalValue)> {
-        self.changes.iter().map(|c| (c.time, &c.value))
+        let times = self.times();
+        self.changes.iter().map(move |c| (Self::resolve(&times, c), &c.value))
     }
-    
+
     /// Iterator over changes after a specific time
     pub fn all_changes_after(&self, start_time: u64) -> impl Iterator<Item = (u64, &SignalValue)> {
-        let start_idx = self.changes.binary_search_by_key(&start_time, |c| c.time)
+        let times = self.times();
+        let start_idx = self.changes
+            .binary_search_by(|c| Self::resolve(&times, c).cmp(&start_time))
             .unwrap_or_else(|idx| idx);
-        
-        self.changes[start_idx..].iter().map(|c| (c.time, &c.value))
+
+        self.changes[start_idx..].iter().map(move |c| (Self::resolve(&times, c), &c.value))
     }
-    
+
+    /// Lending view over `[start_time, end_time]`: every item borrows its
+    /// value straight out of `self.changes`, so scanning a window (e.g. to
+    /// redraw a viewport) never allocates or clones.
+    pub fn changes_in_window(&self, start_time: u64, end_time: u64) -> impl Iterator<Item = (u64, &SignalValue)> {
+        let (start_idx, end_idx) = self.window_bounds(start_time, end_time);
+        let times = self.times();
+        self.changes[start_idx..end_idx]
+            .iter()
+            .map(move |c| (Self::resolve(&times, c), &c.value))
+    }
+
+    /// Index range (end-exclusive) of changes overlapping `[start_time, end_time]`.
+    fn window_bounds(&self, start_time: u64, end_time: u64) -> (usize, usize) {
+        let times = self.times();
+        let start_idx = self.changes
+            .binary_search_by(|c| Self::resolve(&times, c).cmp(&start_time))
+            .unwrap_or_else(|idx| idx);
+        let end_idx = self.changes
+            .binary_search_by(|c| Self::resolve(&times, c).cmp(&end_time))
+            .map(|idx| idx + 1)
+            .unwrap_or_else(|idx| idx);
+        (start_idx, end_idx.max(start_idx))
+    }
+
+    /// Clone just the transitions overlapping `[start_time, end_time]` into a
+    /// new `Signal` sharing this one's `TimeTable`. Used by backends that
+    /// have no cheaper way to produce a windowed signal than decoding the
+    /// whole thing first.
+    pub fn windowed_copy(&self, start_time: u64, end_time: u64) -> Signal {
+        let (start_idx, end_idx) = self.window_bounds(start_time, end_time);
+        Signal {
+            changes: self.changes[start_idx..end_idx].to_vec(),
+            time_table: self.time_table.clone(),
+        }
+    }
+
+    /// Index of the first change strictly after `time` (or `changes.len()`).
+    fn index_after(&self, time: u64) -> usize {
+        let times = self.times();
+        self.changes
+            .binary_search_by(|c| Self::resolve(&times, c).cmp(&time))
+            .map(|idx| idx + 1)
+            .unwrap_or_else(|idx| idx)
+    }
+
+    /// Index of the last change strictly before `time` (or `None`).
+    fn index_before(&self, time: u64) -> Option<usize> {
+        let times = self.times();
+        let idx = self.changes
+            .binary_search_by(|c| Self::resolve(&times, c).cmp(&time))
+            .unwrap_or_else(|idx| idx);
+        idx.checked_sub(1)
+    }
+
+    /// Scan `changes` for the next transition where `value` differs from the
+    /// one before it, starting just after (or before) `from_time`.
+    pub fn find_next_edge(&self, from_time: u64, direction: Direction) -> Option<u64> {
+        self.find_next_match(from_time, direction, |prev, curr| prev != curr)
+    }
+
+    /// Next single-bit low-to-high transition. `None` if this isn't a
+    /// single-bit signal.
+    pub fn find_next_rising(&self, from_time: u64, direction: Direction) -> Option<u64> {
+        self.find_next_match(from_time, direction, |prev, curr| {
+            matches!(
+                (single_bit_state(prev), single_bit_state(curr)),
+                (Some(BitState::Zero), Some(BitState::One))
+            )
+        })
+    }
+
+    /// Next single-bit high-to-low transition. `None` if this isn't a
+    /// single-bit signal.
+    pub fn find_next_falling(&self, from_time: u64, direction: Direction) -> Option<u64> {
+        self.find_next_match(from_time, direction, |prev, curr| {
+            matches!(
+                (single_bit_state(prev), single_bit_state(curr)),
+                (Some(BitState::One), Some(BitState::Zero))
+            )
+        })
+    }
+
+    /// Find the next change (relative to `from_time`, in `direction`) whose
+    /// `(previous value, new value)` pair satisfies `predicate`, returning
+    /// its time. The first change in the signal has no predecessor to pair
+    /// with, so it is never reported, in either direction.
+    pub fn find_next_match<F>(&self, from_time: u64, direction: Direction, mut predicate: F) -> Option<u64>
+    where
+        F: FnMut(&SignalValue, &SignalValue) -> bool,
+    {
+        match direction {
+            Direction::Forward => {
+                let mut idx = self.index_after(from_time).max(1);
+                while idx < self.changes.len() {
+                    if predicate(&self.changes[idx - 1].value, &self.changes[idx].value) {
+                        return Some(self.time_at(idx));
+                    }
+                    idx += 1;
+                }
+                None
+            }
+            Direction::Backward => {
+                let mut idx = self.index_before(from_time)?;
+                while idx >= 1 {
+                    if predicate(&self.changes[idx - 1].value, &self.changes[idx].value) {
+                        return Some(self.time_at(idx));
+                    }
+                    idx -= 1;
+                }
+                None
+            }
+        }
+    }
+
+    /// Find the next change (in `direction`, relative to `from_time`) whose
+    /// new value equals `value`, returning its time.
+    pub fn find_next_value(&self, from_time: u64, value: &SignalValue, direction: Direction) -> Option<u64> {
+        self.find_next_match(from_time, direction, |_prev, curr| curr == value)
+    }
+
+    /// Downsample a real-valued signal over `[start_time, end_time]` into at
+    /// most `4 * buckets` points, so the renderer never has to walk more
+    /// samples than it has pixels for.
+    ///
+    /// The range is split into `buckets` equal-width bins; each bin
+    /// contributes its first, min, max, and last observed value (in time
+    /// order, deduped when two of those coincide) - min/max keep spikes
+    /// visible, and first/last keep a flat or stepped bin's actual edges
+    /// instead of collapsing it to just the extrema. Non-`Real` signals
+    /// yield `None`.
+    pub fn decimate(&self, start_time: u64, end_time: u64, buckets: usize) -> Option<Vec<DecimatedPoint>> {
+        if buckets == 0 || end_time <= start_time {
+            return None;
+        }
+
+        let (start_idx, end_idx) = self.window_bounds(start_time, end_time);
+        if start_idx >= end_idx {
+            return None;
+        }
+
+        let bucket_width = ((end_time - start_time) / buckets as u64).max(1);
+        let mut points = Vec::with_capacity(buckets * 4);
+        let mut idx = start_idx;
+        let times = self.times();
+
+        for bucket in 0..buckets {
+            let is_last_bucket = bucket + 1 == buckets;
+            let bin_start = start_time + bucket as u64 * bucket_width;
+            let bin_end = if is_last_bucket { end_time } else { bin_start + bucket_width };
+
+            let mut first: Option<(u64, f64)> = None;
+            let mut last: Option<(u64, f64)> = None;
+            let mut min: Option<(u64, f64)> = None;
+            let mut max: Option<(u64, f64)> = None;
+
+            // window_bounds includes a change sitting exactly at end_time, so
+            // the last bucket's upper bound must be inclusive or that sample
+            // is walked past here and silently dropped from every bucket.
+            while idx < end_idx {
+                let t = Self::resolve(&times, &self.changes[idx]);
+                if t > bin_end || (t == bin_end && !is_last_bucket) {
+                    break;
+                }
+                if let SignalValue::Real(v) = &self.changes[idx].value {
+                    let v = *v;
+                    first.get_or_insert((t, v));
+                    last = Some((t, v));
+                    if min.map_or(true, |(_, m)| v < m) {
+                        min = Some((t, v));
+                    }
+                    if max.map_or(true, |(_, m)| v > m) {
+                        max = Some((t, v));
+                    }
+                }
+                idx += 1;
+            }
+
+            if let (Some(first_pt), Some(last_pt), Some(min_pt), Some(max_pt)) = (first, last, min, max) {
+                let mut bin_points = [first_pt, min_pt, max_pt, last_pt];
+                bin_points.sort_by_key(|p| p.0);
+                for (time, value) in bin_points {
+                    if points.last().map_or(true, |p: &DecimatedPoint| p.time != time) {
+                        points.push(DecimatedPoint { time, value });
+                    }
+                }
+            }
+        }
+
+        if points.is_empty() {
+            return None;
+        }
+        Some(points)
+    }
+
     /// Query signal at specific time
     pub fn query_signal(&self, query_time: u64) -> QueryResult {
         if self.changes.is_empty() {
@@ -142,8 +533,9 @@ impl Signal {
                 next_time: None,
             };
         }
-        
-        let idx = match self.changes.binary_search_by_key(&query_time, |c| c.time) {
+
+        let times = self.times();
+        let idx = match self.changes.binary_search_by(|c| Self::resolve(&times, c).cmp(&query_time)) {
             Ok(idx) => {
                 // Exact match
                 let next_idx = if idx + 1 < self.changes.len() {
@@ -151,25 +543,25 @@ impl Signal {
                 } else {
                     None
                 };
-                let next_time = next_idx.map(|i| self.changes[i].time);
-                
+                let next_time = next_idx.map(|i| Self::resolve(&times, &self.changes[i]));
+
                 return QueryResult {
                     value: Some(self.changes[idx].value.clone()),
-                    actual_time: Some(self.changes[idx].time),
+                    actual_time: Some(Self::resolve(&times, &self.changes[idx])),
                     next_idx,
                     next_time,
                 };
             }
             Err(idx) => idx,
         };
-        
+
         if idx == 0 {
             // Query time is before first change
             QueryResult {
                 value: None,
                 actual_time: None,
                 next_idx: Some(0),
-                next_time: Some(self.changes[0].time),
+                next_time: Some(Self::resolve(&times, &self.changes[0])),
             }
         } else {
             // Return the last change before query time
@@ -179,11 +571,11 @@ impl Signal {
             } else {
                 None
             };
-            let next_time = next_idx.map(|i| self.changes[i].time);
-            
+            let next_time = next_idx.map(|i| Self::resolve(&times, &self.changes[i]));
+
             QueryResult {
                 value: Some(self.changes[prev_idx].value.clone()),
-                actual_time: Some(self.changes[prev_idx].time),
+                actual_time: Some(Self::resolve(&times, &self.changes[prev_idx])),
                 next_idx,
                 next_time,
             }
@@ -200,122 +592,21 @@ pub struct QueryResult {
     pub next_time: Option<u64>,
 }
 
-/// Time table for compressed time representation
-#[derive(Debug, Clone)]
-pub struct TimeTable {
-    times: Vec<u64>,
-}
-
-impl TimeTable {
-    pub fn new() -> Self {
-        TimeTable { times: Vec::new() }
-    }
-    
-    pub fn from_times(times: Vec<u64>) -> Self {
-        TimeTable { times }
-    }
-    
-    pub fn get(&self, idx: usize) -> Option<u64> {
-        self.times.get(idx).copied()
-    }
-    
-    pub fn len(&self) -> usize {
-        self.times.len()
-    }
-    
-    pub fn is_empty(&self) -> bool {
-        self.times.is_empty()
-    }
-}
-
-// C callback context for signal loading
-struct SignalLoadContext {
-    signal: Arc<Mutex<Signal>>,
-    is_real: bool,
-    is_string: bool,
-}
-
-// C callback function for FST value changes
-unsafe extern "C" fn signal_callback(
-    user_data: *mut std::os::raw::c_void,
-    time: u64,
-    _handle: FstHandle,
-    value: *const u8,  // const unsigned char *
-) {
-    let ctx = &*(user_data as *const SignalLoadContext);
-    
-    // Convert value to string - FST uses null-terminated strings
-    let value_str = if value.is_null() {
-        String::new()
-    } else {
-        // Find the null terminator
-        let mut len = 0;
-        while *value.add(len) != 0 {
-            len += 1;
-        }
-        
-        // Convert to string
-        let slice = std::slice::from_raw_parts(value, len);
-        String::from_utf8_lossy(slice).to_string()
-    };
-    
-    let signal_value = SignalValue::from_fst_string(&value_str, ctx.is_real, ctx.is_string);
-    
-    let mut signal = ctx.signal.lock().unwrap();
-    signal.add_change(time, signal_value);
-}
-
-/// Load signal from FST file
-pub fn load_signal_from_fst(
-    reader: &FstReader,
-    handle: FstHandle,
-    is_real: bool,
-    is_string: bool,
-) -> Result<Signal, String> {
-    let signal = Arc::new(Mutex::new(Signal::new()));
-    
-    // Create context without cloning the Arc
-    {
-        let ctx = SignalLoadContext {
-            signal: signal.clone(),
-            is_real,
-            is_string,
-        };
-        
-        // Clear all masks and set only the one we want
-        reader.clear_fac_process_mask_all();
-        reader.set_fac_process_mask(handle);
-        
-        // Load signal data
-        let ctx_ptr = &ctx as *const _ as *mut std::os::raw::c_void;
-        if !reader.iterate_blocks(Some(signal_callback), ctx_ptr) {
-            return Err("Failed to iterate blocks".to_string());
-        }
-    } // ctx is dropped here, releasing the clone
-    
-    // Now we can extract signal from Arc<Mutex>
-    let signal = Arc::try_unwrap(signal)
-        .map_err(|_| "Failed to unwrap signal Arc")?
-        .into_inner()
-        .map_err(|_| "Failed to unwrap signal Mutex")?;
-    
-    Ok(signal)
-}
-
-/// Signal source for loading and caching signals
+/// Signal source for loading and caching signals, backed by whichever
+/// `SignalBackend` opened the file (FST, VCD, ...).
 pub struct SignalSource {
-    reader: Arc<FstReader>,
+    backend: Arc<dyn SignalBackend>,
     signal_cache: Arc<Mutex<BTreeMap<SignalRef, Arc<Signal>>>>,
 }
 
 impl SignalSource {
-    pub fn new(reader: Arc<FstReader>) -> Self {
+    pub fn new(backend: Arc<dyn SignalBackend>) -> Self {
         SignalSource {
-            reader,
+            backend,
             signal_cache: Arc::new(Mutex::new(BTreeMap::new())),
         }
     }
-    
+
     /// Load a single signal
     pub fn load_signal(
         &self,
@@ -331,9 +622,9 @@ impl SignalSource {
                 return Ok(signal.clone());
             }
         }
-        
-        // Load signal from FST
-        let signal = load_signal_from_fst(&self.reader, handle, is_real, is_string)?;
+
+        // Load signal from the underlying backend
+        let signal = self.backend.load_signal(handle, is_real, is_string)?;
         let signal_arc = Arc::new(signal);
         
         // Store in cache
@@ -345,6 +636,83 @@ impl SignalSource {
         Ok(signal_arc)
     }
     
+    /// Load only the transitions of one signal within `[start_time, end_time]`.
+    ///
+    /// Meant for viewport-driven loading of large dumps: unlike
+    /// [`SignalSource::load_signal`] this never goes through (or populates)
+    /// the full-signal cache, since a window is only ever a sliver of what
+    /// the caller will eventually want for that signal.
+    pub fn load_signal_window(
+        &self,
+        handle: FstHandle,
+        is_real: bool,
+        is_string: bool,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Signal, String> {
+        self.backend.load_signal_window(handle, is_real, is_string, start_time, end_time)
+    }
+
+    /// Find the next edge (any value change) on a signal, loading and
+    /// caching it first if necessary. Lets the UI drive cursor navigation
+    /// ("next edge" / "next rising edge" / ...) without re-scanning the
+    /// whole change vector itself on every keypress.
+    pub fn find_next_edge(
+        &self,
+        signal_ref: SignalRef,
+        handle: FstHandle,
+        is_real: bool,
+        is_string: bool,
+        from_time: u64,
+        direction: Direction,
+    ) -> Result<Option<u64>, String> {
+        let signal = self.load_signal(signal_ref, handle, is_real, is_string)?;
+        Ok(signal.find_next_edge(from_time, direction))
+    }
+
+    /// Find the next rising edge of a single-bit signal.
+    pub fn find_next_rising(
+        &self,
+        signal_ref: SignalRef,
+        handle: FstHandle,
+        is_real: bool,
+        is_string: bool,
+        from_time: u64,
+        direction: Direction,
+    ) -> Result<Option<u64>, String> {
+        let signal = self.load_signal(signal_ref, handle, is_real, is_string)?;
+        Ok(signal.find_next_rising(from_time, direction))
+    }
+
+    /// Find the next falling edge of a single-bit signal.
+    pub fn find_next_falling(
+        &self,
+        signal_ref: SignalRef,
+        handle: FstHandle,
+        is_real: bool,
+        is_string: bool,
+        from_time: u64,
+        direction: Direction,
+    ) -> Result<Option<u64>, String> {
+        let signal = self.load_signal(signal_ref, handle, is_real, is_string)?;
+        Ok(signal.find_next_falling(from_time, direction))
+    }
+
+    /// Find the next time a signal's value equals `value`.
+    pub fn find_next_value(
+        &self,
+        signal_ref: SignalRef,
+        handle: FstHandle,
+        is_real: bool,
+        is_string: bool,
+        from_time: u64,
+        value: &SignalValue,
+        direction: Direction,
+    ) -> Result<Option<u64>, String> {
+        let signal = self.load_signal(signal_ref, handle, is_real, is_string)?;
+        Ok(signal.find_next_value(from_time, value, direction))
+    }
+
     /// Load multiple signals
     pub fn load_signals(
         &self,
@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+
+use crate::backend::SignalBackend;
+use crate::ffi::FstHandle;
+use crate::hierarchy::{
+    Hierarchy, Scope, ScopeRef, ScopeType, Timescale, TimescaleUnit, Var, VarDirection, VarRef,
+    SignalRef,
+};
+use crate::signal::{Signal, SignalValue, TimeTable};
+
+/// Parser/backend for the plain-text VCD format.
+///
+/// Unlike the FST backend (which can selectively decode one signal at a
+/// time via the block process mask) VCD has no index: the whole body has
+/// to be scanned to find any single signal's transitions. So `open` parses
+/// the file once, builds the `Hierarchy` exactly as `Hierarchy::from_fst`
+/// does, and decodes every signal's changes up front; `load_signal` then
+/// just hands back the pre-decoded copy.
+pub struct VcdBackend {
+    hierarchy: Hierarchy,
+    signals: HashMap<FstHandle, Signal>,
+    time_range: (u64, u64),
+    time_table: Arc<TimeTable>,
+}
+
+impl VcdBackend {
+    pub fn open(path: &str) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read VCD file {}: {}", path, e))?;
+        let mut tokens = text.split_whitespace();
+
+        let mut hierarchy = Hierarchy {
+            scopes: Vec::new(),
+            vars: Vec::new(),
+            path_to_var: HashMap::new(),
+            signal_ref_map: HashMap::new(),
+            timescale: None,
+            date: String::new(),
+            version: String::new(),
+            file_format: "VCD".to_string(),
+        };
+
+        let mut scope_stack: Vec<ScopeRef> = Vec::new();
+        let mut current_path: Vec<String> = Vec::new();
+        let mut id_to_handle: HashMap<String, FstHandle> = HashMap::new();
+        let mut handle_widths: HashMap<FstHandle, u32> = HashMap::new();
+        let mut next_handle: FstHandle = 0;
+        let mut signal_counter = 0usize;
+
+        // Header: $date/$version/$timescale/$scope/$var/$upscope, ending at $enddefinitions.
+        while let Some(tok) = tokens.next() {
+            match tok {
+                "$date" => hierarchy.date = collect_until_end(&mut tokens),
+                "$version" => hierarchy.version = collect_until_end(&mut tokens),
+                "$timescale" => {
+                    let spec = collect_until_end(&mut tokens);
+                    hierarchy.timescale = parse_timescale(&spec);
+                }
+                "$scope" => {
+                    let typ = tokens.next().ok_or("Malformed $scope: missing type")?;
+                    let name = tokens.next().ok_or("Malformed $scope: missing name")?.to_string();
+                    expect_end(&mut tokens)?;
+
+                    let scope_type = scope_type_from_keyword(typ);
+                    let parent = scope_stack.last().copied();
+                    let scope_ref = ScopeRef(hierarchy.scopes.len());
+
+                    hierarchy.scopes.push(Scope {
+                        name: name.clone(),
+                        scope_type,
+                        parent,
+                        children: Vec::new(),
+                        vars: Vec::new(),
+                    });
+                    if let Some(parent_ref) = parent {
+                        hierarchy.scopes[parent_ref.0].children.push(scope_ref);
+                    }
+
+                    scope_stack.push(scope_ref);
+                    current_path.push(name);
+                }
+                "$upscope" => {
+                    expect_end(&mut tokens)?;
+                    scope_stack.pop();
+                    current_path.pop();
+                }
+                "$var" => {
+                    let typ = tokens.next().ok_or("Malformed $var: missing type")?;
+                    let width: u32 = tokens
+                        .next()
+                        .ok_or("Malformed $var: missing width")?
+                        .parse()
+                        .map_err(|_| "Malformed $var: bad width".to_string())?;
+                    let id = tokens.next().ok_or("Malformed $var: missing id code")?.to_string();
+
+                    let mut name_parts = Vec::new();
+                    loop {
+                        let t = tokens.next().ok_or("Malformed $var: missing $end")?;
+                        if t == "$end" {
+                            break;
+                        }
+                        name_parts.push(t);
+                    }
+                    let raw_name = name_parts.join(" ");
+
+                    let var_type = var_type_from_keyword(typ);
+                    let handle = *id_to_handle.entry(id).or_insert_with(|| {
+                        let h = next_handle;
+                        next_handle += 1;
+                        h
+                    });
+                    handle_widths.entry(handle).or_insert(width);
+
+                    // Several $var lines can share one id-code (VCD's aliasing), exactly
+                    // as several FST handles can share one signal_ref.
+                    let signal_ref = if let Some(&existing) = hierarchy.signal_ref_map.get(&handle) {
+                        existing
+                    } else {
+                        let new_ref = SignalRef(signal_counter);
+                        hierarchy.signal_ref_map.insert(handle, new_ref);
+                        signal_counter += 1;
+                        new_ref
+                    };
+
+                    let scope = scope_stack.last().copied();
+                    let var_ref = VarRef(hierarchy.vars.len());
+                    let var = Var::new(
+                        raw_name,
+                        var_type,
+                        VarDirection::Unknown,
+                        Some(width),
+                        signal_ref,
+                        handle,
+                        scope,
+                    );
+
+                    let mut full_path = current_path.clone();
+                    full_path.push(var.name.clone());
+                    hierarchy.path_to_var.insert(full_path.join("."), var_ref);
+
+                    hierarchy.vars.push(var);
+                    if let Some(scope_ref) = scope {
+                        hierarchy.scopes[scope_ref.0].vars.push(var_ref);
+                    }
+                }
+                "$enddefinitions" => {
+                    expect_end(&mut tokens)?;
+                    break;
+                }
+                "$comment" | "$dumpall" | "$dumpoff" | "$dumpon" | "$dumpvars" => {
+                    skip_until_end(&mut tokens);
+                }
+                _ => {}
+            }
+        }
+
+        // Body: #<time> markers followed by scalar/vector/real/string value changes.
+        let mut signals: HashMap<FstHandle, Signal> = HashMap::new();
+        let mut time = 0u64;
+        let mut min_time = u64::MAX;
+        let mut max_time = 0u64;
+        let time_table = Arc::new(TimeTable::new());
+
+        while let Some(tok) = tokens.next() {
+            if let Some(rest) = tok.strip_prefix('#') {
+                if let Ok(t) = rest.parse::<u64>() {
+                    time = t;
+                    min_time = min_time.min(time);
+                    max_time = max_time.max(time);
+                }
+                continue;
+            }
+
+            let mut chars = tok.chars();
+            match chars.next() {
+                Some('b') | Some('B') => {
+                    let bits = &tok[1..];
+                    let Some(id) = tokens.next() else { break };
+                    if let Some(&handle) = id_to_handle.get(id) {
+                        // VCD strips leading digits, so the token is shorter than
+                        // the declared width whenever the dropped bits were all
+                        // the same - left-extend back out to that width before
+                        // building the value, or the width is lost.
+                        let width = handle_widths.get(&handle).copied().unwrap_or(bits.len() as u32);
+                        let padded = pad_bit_string(bits, width);
+                        let value = SignalValue::from_fst_string(&padded, false, false);
+                        signals.entry(handle).or_insert_with(|| Signal::new(time_table.clone())).add_change(time, value);
+                    }
+                }
+                Some('r') | Some('R') => {
+                    let num = &tok[1..];
+                    let Some(id) = tokens.next() else { break };
+                    if let Some(&handle) = id_to_handle.get(id) {
+                        let value = SignalValue::from_fst_string(num, true, false);
+                        signals.entry(handle).or_insert_with(|| Signal::new(time_table.clone())).add_change(time, value);
+                    }
+                }
+                Some('s') | Some('S') => {
+                    let s = &tok[1..];
+                    let Some(id) = tokens.next() else { break };
+                    if let Some(&handle) = id_to_handle.get(id) {
+                        signals
+                            .entry(handle)
+                            .or_insert_with(|| Signal::new(time_table.clone()))
+                            .add_change(time, SignalValue::String(s.to_string()));
+                    }
+                }
+                Some(v @ ('0' | '1' | 'x' | 'X' | 'z' | 'Z')) => {
+                    let id: String = chars.collect();
+                    if id.is_empty() {
+                        continue;
+                    }
+                    if let Some(&handle) = id_to_handle.get(id.as_str()) {
+                        let value = SignalValue::from_fst_string(&v.to_string(), false, false);
+                        signals.entry(handle).or_insert_with(|| Signal::new(time_table.clone())).add_change(time, value);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if min_time == u64::MAX {
+            min_time = 0;
+        }
+
+        Ok(VcdBackend {
+            hierarchy,
+            signals,
+            time_range: (min_time, max_time),
+            time_table,
+        })
+    }
+}
+
+impl SignalBackend for VcdBackend {
+    fn hierarchy(&self) -> Result<Hierarchy, String> {
+        Ok(self.hierarchy.clone())
+    }
+
+    fn load_signal(&self, handle: FstHandle, _is_real: bool, _is_string: bool) -> Result<Signal, String> {
+        if let Some(signal) = self.signals.get(&handle) {
+            return Ok(signal.clone());
+        }
+
+        // A handle can be declared (`$var ... $end`) but never appear in the
+        // body at all - a static signal that's simply never toggled. That's
+        // not an error; it's an empty signal, same as `FstBackend` returns
+        // for the same case.
+        if self.hierarchy.signal_ref_map.contains_key(&handle) {
+            return Ok(Signal::new(self.time_table.clone()));
+        }
+
+        Err(format!("Unknown VCD signal handle: {}", handle))
+    }
+
+    fn time_range(&self) -> (u64, u64) {
+        self.time_range
+    }
+
+    fn time_table(&self) -> Arc<TimeTable> {
+        self.time_table.clone()
+    }
+}
+
+fn collect_until_end<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> String {
+    let mut parts = Vec::new();
+    for t in tokens {
+        if t == "$end" {
+            break;
+        }
+        parts.push(t);
+    }
+    parts.join(" ")
+}
+
+fn expect_end<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<(), String> {
+    match tokens.next() {
+        Some("$end") => Ok(()),
+        Some(other) => Err(format!("Expected $end, found '{}'", other)),
+        None => Err("Unexpected end of file, expected $end".to_string()),
+    }
+}
+
+fn skip_until_end<'a>(tokens: &mut impl Iterator<Item = &'a str>) {
+    for t in tokens {
+        if t == "$end" {
+            break;
+        }
+    }
+}
+
+/// Left-extend a `b`/`B` vector token out to its declared width. VCD writers
+/// drop leading bits that equal the fill value, so `b10 !` on an 8-bit var
+/// means `00000010`, not a 2-bit value. Per the VCD extension rules, the
+/// fill is `0` unless the leftmost remaining bit is `x`/`X` or `z`/`Z`, in
+/// which case that state extends instead.
+fn pad_bit_string(bits: &str, width: u32) -> std::borrow::Cow<'_, str> {
+    let width = width as usize;
+    if bits.len() >= width {
+        return std::borrow::Cow::Borrowed(bits);
+    }
+    let fill = match bits.chars().next() {
+        Some(c @ ('x' | 'X' | 'z' | 'Z')) => c,
+        _ => '0',
+    };
+    let mut padded = String::with_capacity(width);
+    for _ in 0..(width - bits.len()) {
+        padded.push(fill);
+    }
+    padded.push_str(bits);
+    std::borrow::Cow::Owned(padded)
+}
+
+fn scope_type_from_keyword(kw: &str) -> ScopeType {
+    match kw {
+        "module" => ScopeType::Module,
+        "task" => ScopeType::Task,
+        "function" => ScopeType::Function,
+        "begin" => ScopeType::Begin,
+        "fork" => ScopeType::Fork,
+        "generate" => ScopeType::Generate,
+        // SystemVerilog/VHDL extensions some simulators emit into VCD's
+        // $scope keyword, beyond the plain Verilog set above.
+        "struct" => ScopeType::Struct,
+        "union" => ScopeType::Union,
+        "class" => ScopeType::Class,
+        "interface" => ScopeType::Interface,
+        "package" => ScopeType::Package,
+        "program" => ScopeType::Program,
+        _ => ScopeType::Unknown,
+    }
+}
+
+fn var_type_from_keyword(kw: &str) -> crate::hierarchy::VarType {
+    use crate::hierarchy::VarType;
+    match kw {
+        "wire" => VarType::Wire,
+        "reg" => VarType::Reg,
+        "integer" => VarType::Integer,
+        "parameter" => VarType::Parameter,
+        "real" => VarType::Real,
+        "realtime" => VarType::RealTime,
+        "time" => VarType::Time,
+        "event" => VarType::Event,
+        "supply0" => VarType::Supply0,
+        "supply1" => VarType::Supply1,
+        "tri" => VarType::Tri,
+        "triand" => VarType::TriAnd,
+        "trior" => VarType::TriOr,
+        "trireg" => VarType::TriReg,
+        "tri0" => VarType::Tri0,
+        "tri1" => VarType::Tri1,
+        "wand" => VarType::WAnd,
+        "wor" => VarType::WOr,
+        "string" => VarType::String,
+        "port" => VarType::Port,
+        _ => VarType::Wire,
+    }
+}
+
+/// Parse a `$timescale` body like `"1 ns"` or `"10ns"`.
+fn parse_timescale(spec: &str) -> Option<Timescale> {
+    let spec: String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    let split_at = spec.find(|c: char| c.is_alphabetic())?;
+    let (num, unit) = spec.split_at(split_at);
+    let factor: u32 = num.parse().ok()?;
+    let unit = match unit {
+        "s" => TimescaleUnit::Seconds,
+        "ms" => TimescaleUnit::Milliseconds,
+        "us" => TimescaleUnit::Microseconds,
+        "ns" => TimescaleUnit::Nanoseconds,
+        "ps" => TimescaleUnit::Picoseconds,
+        "fs" => TimescaleUnit::Femtoseconds,
+        _ => TimescaleUnit::Unknown,
+    };
+    Some(Timescale::new(factor, unit))
+}
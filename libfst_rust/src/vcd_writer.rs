@@ -0,0 +1,242 @@
+//! Serialize a `Hierarchy` plus a chosen set of already-loaded `Signal`s
+//! back out to a standard VCD stream - the inverse of [`crate::vcd`]'s
+//! reader. Lets a caller crop/convert an FST (or VCD) capture to a
+//! shareable VCD file covering just the vars and time window it cares
+//! about.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::hierarchy::{Hierarchy, Scope, SignalRef, TimescaleUnit, Var};
+use crate::signal::{BitState, Signal, SignalValue};
+
+/// One signal to emit, keyed by `SignalRef` so vars that alias the same
+/// underlying signal (several `$var` lines sharing one code) only need one
+/// entry - exactly how the reader collapses them coming in.
+pub struct VcdExportVar<'a> {
+    pub signal_ref: SignalRef,
+    pub signal: &'a Signal,
+}
+
+/// Write `hierarchy`'s scope tree and `vars` as a VCD stream, restricted to
+/// `[start_time, end_time]`. Only vars whose `signal_ref` is present in
+/// `vars` are declared/emitted; the rest of the scope tree is walked but
+/// produces no `$var` lines.
+pub fn write_vcd<W: Write>(
+    hierarchy: &Hierarchy,
+    vars: &[VcdExportVar<'_>],
+    start_time: u64,
+    end_time: u64,
+    out: &mut W,
+) -> io::Result<()> {
+    if !hierarchy.date.is_empty() {
+        writeln!(out, "$date\n\t{}\n$end", hierarchy.date)?;
+    }
+    if !hierarchy.version.is_empty() {
+        writeln!(out, "$version\n\t{}\n$end", hierarchy.version)?;
+    }
+    if let Some(ts) = &hierarchy.timescale {
+        writeln!(out, "$timescale {}{} $end", ts.factor, timescale_unit_str(ts.unit))?;
+    }
+
+    let signals: HashMap<SignalRef, &Signal> = vars.iter().map(|v| (v.signal_ref, v.signal)).collect();
+    let id_codes = assign_id_codes(&signals);
+
+    for scope in hierarchy.top_scopes() {
+        write_scope(hierarchy, scope, &signals, &id_codes, out)?;
+    }
+    writeln!(out, "$enddefinitions $end")?;
+
+    write_body(&signals, &id_codes, start_time, end_time, out)
+}
+
+/// Assign one compact id-code per distinct `signal_ref`, so aliases of the
+/// same underlying signal share a code.
+fn assign_id_codes(signals: &HashMap<SignalRef, &Signal>) -> HashMap<SignalRef, String> {
+    signals
+        .keys()
+        .enumerate()
+        .map(|(i, &signal_ref)| (signal_ref, id_code(i as u32)))
+        .collect()
+}
+
+/// Compact printable id-code, counting through the 94 printable ASCII
+/// characters (`!`..`~`) the way VCD writers conventionally do, then
+/// carrying into additional characters once those are exhausted.
+fn id_code(mut n: u32) -> String {
+    const FIRST: u32 = b'!' as u32;
+    const RADIX: u32 = b'~' as u32 - b'!' as u32 + 1;
+    let mut chars = Vec::new();
+    loop {
+        chars.push(char::from_u32(FIRST + n % RADIX).unwrap());
+        n /= RADIX;
+        if n == 0 {
+            break;
+        }
+        n -= 1;
+    }
+    chars.into_iter().collect()
+}
+
+fn write_scope<W: Write>(
+    hierarchy: &Hierarchy,
+    scope: &Scope,
+    signals: &HashMap<SignalRef, &Signal>,
+    id_codes: &HashMap<SignalRef, String>,
+    out: &mut W,
+) -> io::Result<()> {
+    writeln!(out, "$scope {} {} $end", scope_type_keyword(scope), scope.name)?;
+
+    for &var_ref in &scope.vars {
+        if let Some(var) = hierarchy.get_var(var_ref) {
+            if signals.contains_key(&var.signal_ref) {
+                write_var_decl(var, &id_codes[&var.signal_ref], out)?;
+            }
+        }
+    }
+
+    for &child_ref in &scope.children {
+        if let Some(child) = hierarchy.get_scope(child_ref) {
+            write_scope(hierarchy, child, signals, id_codes, out)?;
+        }
+    }
+
+    writeln!(out, "$upscope $end")
+}
+
+fn write_var_decl<W: Write>(var: &Var, id_code: &str, out: &mut W) -> io::Result<()> {
+    let width = var.bitwidth().unwrap_or(1);
+    writeln!(
+        out,
+        "$var {} {} {} {} $end",
+        var_type_keyword(var),
+        width,
+        id_code,
+        var.name
+    )
+}
+
+fn write_body<W: Write>(
+    signals: &HashMap<SignalRef, &Signal>,
+    id_codes: &HashMap<SignalRef, String>,
+    start_time: u64,
+    end_time: u64,
+    out: &mut W,
+) -> io::Result<()> {
+    // One (signal_ref, signal, peekable cursor) per requested signal, merged
+    // by next timestamp in a single pass over every cursor.
+    let mut cursors: Vec<_> = signals
+        .iter()
+        .map(|(&signal_ref, &signal)| (signal_ref, signal, signal.changes_in_window(start_time, end_time).peekable()))
+        .collect();
+
+    // A signal whose last transition before `start_time` falls outside the
+    // window contributes no change to the merge below, so a reader opening
+    // the cropped file would see it as uninitialized for the whole window.
+    // Carry its value at `start_time` forward under an initial marker,
+    // unless an in-window change already lands exactly on `start_time` and
+    // will supply it there.
+    let mut wrote_initial = false;
+    for (signal_ref, signal, cursor) in cursors.iter_mut() {
+        if cursor.peek().map(|&(t, _)| t) == Some(start_time) {
+            continue;
+        }
+        if let Some(value) = signal.value_at_time(start_time) {
+            if !wrote_initial {
+                writeln!(out, "#{}", start_time)?;
+                wrote_initial = true;
+            }
+            write_value_change(out, value, &id_codes[signal_ref])?;
+        }
+    }
+
+    loop {
+        let next_time = cursors.iter_mut().filter_map(|(_, _, c)| c.peek().map(|(t, _)| *t)).min();
+        let Some(t) = next_time else { break };
+
+        writeln!(out, "#{}", t)?;
+        for (signal_ref, _, cursor) in cursors.iter_mut() {
+            while let Some(&(change_time, _)) = cursor.peek() {
+                if change_time != t {
+                    break;
+                }
+                let (_, value) = cursor.next().unwrap();
+                write_value_change(out, value, &id_codes[signal_ref])?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_value_change<W: Write>(out: &mut W, value: &SignalValue, id_code: &str) -> io::Result<()> {
+    match value {
+        SignalValue::Real(r) => writeln!(out, "r{} {}", r, id_code),
+        SignalValue::String(s) => writeln!(out, "s{} {}", s, id_code),
+        SignalValue::Bits(bits) if bits.width() == 1 => {
+            writeln!(out, "{}{}", scalar_char(bits.get(0)), id_code)
+        }
+        SignalValue::Bits(bits) => writeln!(out, "b{} {}", bits.to_string_repr(), id_code),
+    }
+}
+
+fn scalar_char(state: BitState) -> char {
+    match state {
+        BitState::Zero => '0',
+        BitState::One => '1',
+        BitState::X => 'x',
+        BitState::Z => 'z',
+    }
+}
+
+fn timescale_unit_str(unit: TimescaleUnit) -> &'static str {
+    match unit {
+        TimescaleUnit::Zeptoseconds => "zs",
+        TimescaleUnit::Attoseconds => "as",
+        TimescaleUnit::Femtoseconds => "fs",
+        TimescaleUnit::Picoseconds => "ps",
+        TimescaleUnit::Nanoseconds => "ns",
+        TimescaleUnit::Microseconds => "us",
+        TimescaleUnit::Milliseconds => "ms",
+        TimescaleUnit::Seconds => "s",
+        TimescaleUnit::Unknown => "ns",
+    }
+}
+
+fn scope_type_keyword(scope: &Scope) -> &'static str {
+    use crate::hierarchy::ScopeType;
+    match scope.scope_type {
+        ScopeType::Module => "module",
+        ScopeType::Task => "task",
+        ScopeType::Function => "function",
+        ScopeType::Begin => "begin",
+        ScopeType::Fork => "fork",
+        ScopeType::Generate => "generate",
+        _ => "module",
+    }
+}
+
+fn var_type_keyword(var: &Var) -> &'static str {
+    use crate::hierarchy::VarType;
+    match var.var_type {
+        VarType::Event => "event",
+        VarType::Integer => "integer",
+        VarType::Parameter => "parameter",
+        VarType::Real | VarType::RealTime | VarType::ShortReal => "real",
+        VarType::Reg => "reg",
+        VarType::Supply0 => "supply0",
+        VarType::Supply1 => "supply1",
+        VarType::Time => "time",
+        VarType::Tri => "tri",
+        VarType::TriAnd => "triand",
+        VarType::TriOr => "trior",
+        VarType::TriReg => "trireg",
+        VarType::Tri0 => "tri0",
+        VarType::Tri1 => "tri1",
+        VarType::WAnd => "wand",
+        VarType::Wire => "wire",
+        VarType::WOr => "wor",
+        VarType::String => "string",
+        _ => "wire",
+    }
+}
@@ -1,68 +1,65 @@
 use std::sync::Arc;
 
-use crate::ffi::FstReader;
+use crate::backend::{open_backend, SignalBackend};
 use crate::hierarchy::{Hierarchy, Var};
-use crate::signal::{Signal, SignalSource, TimeTable};
+use crate::signal::{Signal, SignalSource};
 
 /// Main waveform structure
 pub struct Waveform {
     pub hierarchy: Arc<Hierarchy>,
     pub wave_source: Option<Arc<SignalSource>>,
     pub time_range: Option<(u64, u64)>,  // (start_time, end_time)
-    reader: Option<Arc<FstReader>>,
+    backend: Option<Arc<dyn SignalBackend>>,
     multi_threaded: bool,
 }
 
 impl Waveform {
-    /// Create new waveform from FST file
+    /// Create new waveform, auto-detecting the file format (FST or VCD today)
     pub fn new(
         path: &str,
         multi_threaded: bool,
         _remove_scopes_with_empty_name: bool,
         load_body: bool,
     ) -> Result<Self, String> {
-        // Open FST file
-        let reader = FstReader::open(path)?;
-        let reader_arc = Arc::new(reader);
-        
+        // Open whichever backend matches the file
+        let backend: Arc<dyn SignalBackend> = Arc::from(open_backend(path)?);
+
         // Parse hierarchy
-        let hierarchy = Hierarchy::from_fst(&reader_arc)?;
+        let hierarchy = backend.hierarchy()?;
         let hierarchy_arc = Arc::new(hierarchy);
-        
+
         let mut waveform = Waveform {
             hierarchy: hierarchy_arc,
             wave_source: None,
             time_range: None,
-            reader: Some(reader_arc.clone()),
+            backend: Some(backend),
             multi_threaded,
         };
-        
+
         // Load body if requested
         if load_body {
             waveform.load_body()?;
         }
-        
+
         Ok(waveform)
     }
-    
+
     /// Load waveform body (time range and signal source)
     pub fn load_body(&mut self) -> Result<(), String> {
         if self.wave_source.is_some() {
             return Ok(()); // Already loaded
         }
-        
-        let reader = self.reader.as_ref()
-            .ok_or_else(|| "No reader available".to_string())?;
-        
-        // Store time range from FST
-        let start_time = reader.start_time();
-        let end_time = reader.end_time();
-        self.time_range = Some((start_time, end_time));
-        
+
+        let backend = self.backend.as_ref()
+            .ok_or_else(|| "No backend available".to_string())?;
+
+        // Store time range from the backend
+        self.time_range = Some(backend.time_range());
+
         // Create signal source
-        let signal_source = SignalSource::new(reader.clone());
+        let signal_source = SignalSource::new(backend.clone());
         self.wave_source = Some(Arc::new(signal_source));
-        
+
         Ok(())
     }
     
@@ -90,6 +87,24 @@ impl Waveform {
         )
     }
     
+    /// Get only the portion of a signal within `[start_time, end_time]`,
+    /// e.g. to redraw a viewport without loading the whole signal.
+    pub fn get_signal_window(
+        &mut self,
+        var: &Var,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Signal, String> {
+        if !self.body_loaded() {
+            self.load_body()?;
+        }
+
+        let wave_source = self.wave_source.as_ref()
+            .ok_or_else(|| "Wave source not available".to_string())?;
+
+        wave_source.load_signal_window(var.fst_handle, var.is_real(), var.is_string(), start_time, end_time)
+    }
+
     /// Get signal from absolute hierarchy path
     pub fn get_signal_from_path(&mut self, abs_hierarchy_path: &str) -> Result<Arc<Signal>, String> {
         // Clone the variable to avoid borrow issues
@@ -168,4 +183,25 @@ impl Waveform {
             wave_source.unload_signals(signal_refs);
         }
     }
+
+    /// Export `vars` (each paired with its already-loaded `Signal`) to a
+    /// standard VCD stream, restricted to `[start_time, end_time]`. The
+    /// inverse of opening a `.vcd` file with `Waveform::new`.
+    pub fn export_vcd<W: std::io::Write>(
+        &self,
+        vars: &[(Var, Arc<Signal>)],
+        start_time: u64,
+        end_time: u64,
+        out: &mut W,
+    ) -> Result<(), String> {
+        let export_vars: Vec<_> = vars
+            .iter()
+            .map(|(var, signal)| crate::vcd_writer::VcdExportVar {
+                signal_ref: var.signal_ref,
+                signal,
+            })
+            .collect();
+        crate::vcd_writer::write_vcd(&self.hierarchy, &export_vars, start_time, end_time, out)
+            .map_err(|e| format!("Failed to write VCD: {}", e))
+    }
 }
\ No newline at end of file
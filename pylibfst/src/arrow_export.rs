@@ -0,0 +1,123 @@
+//! Columnar export of [`Signal`] changes to Apache Arrow (and, from there,
+//! to Parquet). Builds the record batch directly from Rust with arrow-rs
+//! builders so a multi-million-transition signal never has to cross the GIL
+//! one value at a time.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float64Builder, StringBuilder, UInt64Builder};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+
+use crate::signal::{Signal, SignalValue};
+
+/// Arrow type chosen for a signal's `value` column, decided by one pass
+/// over its changes. Shared with [`crate::numpy_export`], which maps
+/// `Utf8` to NumPy's object-array fallback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ValueColumnKind {
+    Float64,
+    UInt64,
+    Utf8,
+}
+
+/// `Real` everywhere picks `Float64`; any `String`/four-state (`x`/`z`)
+/// value forces `Utf8`; plain binary picks `UInt64` if every change fits in
+/// 64 bits, otherwise falls back to `Utf8` (the signal's bit-string
+/// representation), since Arrow has no native >64-bit integer type. A
+/// signal whose changes disagree on which of these applies (e.g. a binary
+/// signal that widens past 64 bits partway through) also resolves to
+/// `Utf8`, the one representation that can hold every value losslessly.
+pub(crate) fn value_column_kind(signal: &Signal) -> ValueColumnKind {
+    let mut kind = None;
+    for (_, value) in signal.all_changes() {
+        let this = match value {
+            SignalValue::Real(_) => ValueColumnKind::Float64,
+            SignalValue::String(_) | SignalValue::FourValue(_) => ValueColumnKind::Utf8,
+            SignalValue::Binary(bits) => {
+                if bits.len() <= 64 {
+                    ValueColumnKind::UInt64
+                } else {
+                    ValueColumnKind::Utf8
+                }
+            }
+        };
+        kind = Some(match kind {
+            None => this,
+            Some(prev) if prev == this => prev,
+            Some(_) => ValueColumnKind::Utf8,
+        });
+    }
+    kind.unwrap_or(ValueColumnKind::UInt64)
+}
+
+/// Build a two-column (`time: UInt64`, `value`) record batch from every
+/// transition in `signal`. An empty signal yields a valid, empty batch with
+/// a `UInt64` value column rather than erroring.
+pub fn signal_to_record_batch(signal: &Signal) -> Result<RecordBatch, String> {
+    let kind = value_column_kind(signal);
+    let len = signal.changes.len();
+
+    let mut time_builder = UInt64Builder::with_capacity(len);
+    let value_array: ArrayRef = match kind {
+        ValueColumnKind::Float64 => {
+            let mut values = Float64Builder::with_capacity(len);
+            for (time, value) in signal.all_changes() {
+                time_builder.append_value(time);
+                let real = match value {
+                    SignalValue::Real(r) => *r,
+                    other => other.to_string_repr().parse::<f64>().unwrap_or(f64::NAN),
+                };
+                values.append_value(real);
+            }
+            Arc::new(values.finish())
+        }
+        ValueColumnKind::UInt64 => {
+            let mut values = UInt64Builder::with_capacity(len);
+            for (time, value) in signal.all_changes() {
+                time_builder.append_value(time);
+                values.append_value(value.to_int().unwrap_or(0));
+            }
+            Arc::new(values.finish())
+        }
+        ValueColumnKind::Utf8 => {
+            let mut values = StringBuilder::new();
+            for (time, value) in signal.all_changes() {
+                time_builder.append_value(time);
+                values.append_value(value.to_string_repr());
+            }
+            Arc::new(values.finish())
+        }
+    };
+
+    let value_type = match kind {
+        ValueColumnKind::Float64 => DataType::Float64,
+        ValueColumnKind::UInt64 => DataType::UInt64,
+        ValueColumnKind::Utf8 => DataType::Utf8,
+    };
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("time", DataType::UInt64, false),
+        Field::new("value", value_type, false),
+    ]));
+
+    let time_array: ArrayRef = Arc::new(time_builder.finish());
+    RecordBatch::try_new(schema, vec![time_array, value_array])
+        .map_err(|e| format!("Failed to build record batch: {}", e))
+}
+
+/// Write `batch` to a Parquet file at `path`, overwriting it if present.
+pub fn write_record_batch_to_parquet(batch: &RecordBatch, path: &str) -> Result<(), String> {
+    use parquet::arrow::ArrowWriter;
+    use std::fs::File;
+
+    let file = File::create(path).map_err(|e| format!("Failed to create {}: {}", path, e))?;
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None)
+        .map_err(|e| format!("Failed to create parquet writer: {}", e))?;
+    writer
+        .write(batch)
+        .map_err(|e| format!("Failed to write record batch: {}", e))?;
+    writer
+        .close()
+        .map_err(|e| format!("Failed to finalize {}: {}", path, e))?;
+    Ok(())
+}
@@ -1,6 +1,9 @@
+mod arrow_export;
 mod ffi;
 mod hierarchy;
+mod numpy_export;
 mod signal;
+mod typed_value;
 mod waveform;
 
 use pyo3::prelude::*;
@@ -368,28 +371,65 @@ impl PyScopeIter {
 #[derive(Clone)]
 struct PySignal {
     inner: Arc<signal::Signal>,
+    // Which signal this is, so `PyWaveform::unload_signals` can actually
+    // evict it from the cache it came from instead of guessing `SignalRef(0)`.
+    signal_ref: SignalRef,
+    // Declared type/width of the variable this signal came from, used by
+    // `value_at_time(time, decoded=True)` to pick a signed/unsigned/bool
+    // interpretation.
+    var_type: hierarchy::VarType,
+    bitwidth: Option<u32>,
+    enum_def: Option<Arc<typed_value::EnumDef>>,
 }
 
 #[pymethods]
 impl PySignal {
-    fn value_at_time(&self, time: u64) -> PyObject {
-        Python::with_gil(|py| {
-            match self.inner.value_at_time(time) {
-                Some(SignalValue::Binary(ref bits)) => {
-                    // Convert to integer
-                    if let Some(val) = SignalValue::Binary(bits.clone()).to_int() {
-                        val.into_py(py)
-                    } else {
-                        // Too large for u64, return as string
-                        SignalValue::Binary(bits.clone()).to_string_repr().into_py(py)
-                    }
+    /// With `decoded=False` (the default), the raw value as an unsigned
+    /// int/float/string - the same behavior as always. With
+    /// `decoded=True`, a [`PyDecodedValue`] that interprets the bits using
+    /// this signal's declared `VarType`/width (signed ints, 1-bit bools)
+    /// and, if an [`PyEnumDef`] was attached via `set_enum_def`, resolves
+    /// the symbolic name instead.
+    #[pyo3(signature = (time, decoded = false))]
+    fn value_at_time(&self, time: u64, decoded: bool, py: Python<'_>) -> PyObject {
+        let raw = self.inner.value_at_time(time);
+
+        if decoded {
+            return match raw {
+                Some(value) => {
+                    let converted = typed_value::decode_value(
+                        &value,
+                        self.var_type,
+                        self.bitwidth,
+                        self.enum_def.as_deref(),
+                    );
+                    converted_to_pydecoded(py, converted).into_py(py)
                 }
-                Some(SignalValue::FourValue(ref s)) => s.clone().into_py(py),
-                Some(SignalValue::Real(r)) => r.into_py(py),
-                Some(SignalValue::String(ref s)) => s.clone().into_py(py),
                 None => py.None(),
+            };
+        }
+
+        match raw {
+            Some(SignalValue::Binary(ref bits)) => {
+                // Convert to integer
+                if let Some(val) = SignalValue::Binary(bits.clone()).to_int() {
+                    val.into_py(py)
+                } else {
+                    // Too large for u64, return as string
+                    SignalValue::Binary(bits.clone()).to_string_repr().into_py(py)
+                }
             }
-        })
+            Some(SignalValue::FourValue(ref s)) => s.clone().into_py(py),
+            Some(SignalValue::Real(r)) => r.into_py(py),
+            Some(SignalValue::String(ref s)) => s.clone().into_py(py),
+            None => py.None(),
+        }
+    }
+
+    /// Attach (or, with `None`, clear) the enum symbol table used by
+    /// `value_at_time(time, decoded=True)`.
+    fn set_enum_def(&mut self, def: Option<PyEnumDef>) {
+        self.enum_def = def.map(|d| d.inner);
     }
     
     fn value_at_idx(&self, idx: usize) -> PyObject {
@@ -483,6 +523,40 @@ impl PySignal {
             next_time: result.next_time,
         }
     }
+
+    /// Columnar view of every transition as a two-column Arrow record batch
+    /// (`time: UInt64`, `value: ...`), handed to Python zero-copy through
+    /// the Arrow C Data Interface so pyarrow/pandas/polars can consume it
+    /// without looping over the GIL.
+    fn to_arrow(&self) -> PyResult<arrow::pyarrow::PyArrowType<arrow::record_batch::RecordBatch>> {
+        arrow_export::signal_to_record_batch(&self.inner)
+            .map(arrow::pyarrow::PyArrowType)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
+    }
+
+    /// Write every transition to a Parquet file at `path`.
+    fn to_parquet(&self, path: &str) -> PyResult<()> {
+        let batch = arrow_export::signal_to_record_batch(&self.inner)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))?;
+        arrow_export::write_record_batch_to_parquet(&batch, path)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
+    }
+
+    /// `(times, values)` for every transition as NumPy arrays built in Rust,
+    /// so converting a large trace doesn't pay a per-value GIL round-trip.
+    fn all_changes_numpy(&self, py: Python<'_>) -> PyResult<(Py<numpy::PyArray1<u64>>, PyObject)> {
+        numpy_export::signal_all_changes_numpy(py, &self.inner)
+    }
+
+    /// Resample this signal onto `times` (sorted, non-decreasing), carrying
+    /// the last-known value forward, and return the result as one NumPy
+    /// column - a single merge walk over `times` and this signal's changes
+    /// rather than one query per requested time.
+    fn sample_at_times(&self, times: Vec<u64>, py: Python<'_>) -> PyObject {
+        let signal = self.inner.clone();
+        let samples = py.allow_threads(move || signal.sample_at_times(&times));
+        numpy_export::sampled_values_to_numpy(py, &samples)
+    }
 }
 
 /// Python iterator for signal changes
@@ -527,6 +601,53 @@ struct PyQueryResult {
     next_time: Option<u64>,
 }
 
+/// Raw-bit-string -> symbolic name table, attached to a `Signal` via
+/// `Signal.set_enum_def` so `value_at_time(time, decoded=True)` resolves
+/// enum states by name instead of raw bit pattern.
+#[pyclass(name = "EnumDef")]
+#[derive(Clone)]
+struct PyEnumDef {
+    inner: Arc<typed_value::EnumDef>,
+}
+
+#[pymethods]
+impl PyEnumDef {
+    #[new]
+    fn new(mapping: Vec<(String, String)>) -> Self {
+        PyEnumDef {
+            inner: Arc::new(typed_value::EnumDef::new(mapping)),
+        }
+    }
+}
+
+/// Result of `Signal.value_at_time(time, decoded=True)`: `kind` is the
+/// stable discriminant (`"signed"`, `"unsigned"`, `"bool"`, `"float"`,
+/// `"text"`, `"enum"`, or `"raw"`), `value` the decoded Python value.
+#[pyclass(name = "DecodedValue")]
+struct PyDecodedValue {
+    #[pyo3(get)]
+    kind: String,
+    #[pyo3(get)]
+    value: PyObject,
+}
+
+fn converted_to_pydecoded(py: Python<'_>, converted: typed_value::ConvertedValue) -> PyDecodedValue {
+    use typed_value::ConvertedValue;
+    let (kind, value) = match converted {
+        ConvertedValue::Raw(s) => ("raw", s.into_py(py)),
+        ConvertedValue::Signed(v) => ("signed", v.into_py(py)),
+        ConvertedValue::Unsigned(v) => ("unsigned", v.into_py(py)),
+        ConvertedValue::Bool(b) => ("bool", b.into_py(py)),
+        ConvertedValue::Float(f) => ("float", f.into_py(py)),
+        ConvertedValue::Text(s) => ("text", s.into_py(py)),
+        ConvertedValue::Enum(s) => ("enum", s.into_py(py)),
+    };
+    PyDecodedValue {
+        kind: kind.to_string(),
+        value,
+    }
+}
+
 /// Python wrapper for TimeTable
 #[pyclass(name = "TimeTable")]
 #[derive(Clone)]
@@ -544,6 +665,11 @@ impl PyTimeTable {
     fn __len__(&self) -> usize {
         self.inner.len()
     }
+
+    /// The whole table as a contiguous `uint64` ndarray.
+    fn as_numpy(&self, py: Python<'_>) -> Py<numpy::PyArray1<u64>> {
+        numpy_export::time_table_to_numpy(py, &self.inner)
+    }
 }
 
 /// Main Waveform class
@@ -596,28 +722,38 @@ impl PyWaveform {
     fn get_signal(&mut self, var: &PyVar, py: Python) -> PyResult<PySignal> {
         // Release GIL for I/O operation
         py.allow_threads(|| {
+            let signal_ref = var.inner.signal_ref;
+            let var_type = var.inner.var_type;
+            let bitwidth = var.inner.bitwidth();
             self.inner.get_signal(&var.inner)
-                .map(|signal| PySignal { inner: signal })
+                .map(|signal| PySignal { inner: signal, signal_ref, var_type, bitwidth, enum_def: None })
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
         })
     }
     
     fn get_signal_from_path(&mut self, abs_hierarchy_path: &str, py: Python) -> PyResult<PySignal> {
         py.allow_threads(|| {
+            let (signal_ref, var_type, bitwidth) = self.inner.hierarchy.var_by_path(abs_hierarchy_path)
+                .map(|var| (var.signal_ref, var.var_type, var.bitwidth()))
+                .ok_or_else(|| format!("Variable not found: {}", abs_hierarchy_path))?;
             self.inner.get_signal_from_path(abs_hierarchy_path)
-                .map(|signal| PySignal { inner: signal })
+                .map(|signal| PySignal { inner: signal, signal_ref, var_type, bitwidth, enum_def: None })
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
         })
     }
     
     fn load_signals(&mut self, vars: Vec<PyVar>, py: Python) -> PyResult<Vec<PySignal>> {
         let rust_vars: Vec<_> = vars.iter().map(|v| v.inner.clone()).collect();
-        
+        let meta: Vec<_> = rust_vars.iter().map(|v| (v.signal_ref, v.var_type, v.bitwidth())).collect();
+
         py.allow_threads(|| {
             self.inner.load_signals(&rust_vars)
                 .map(|signals| {
                     signals.into_iter()
-                        .map(|s| PySignal { inner: s })
+                        .zip(meta.into_iter())
+                        .map(|(s, (signal_ref, var_type, bitwidth))| {
+                            PySignal { inner: s, signal_ref, var_type, bitwidth, enum_def: None }
+                        })
                         .collect()
                 })
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
@@ -626,24 +762,60 @@ impl PyWaveform {
     
     fn load_signals_multithreaded(&mut self, vars: Vec<PyVar>, py: Python) -> PyResult<Vec<PySignal>> {
         let rust_vars: Vec<_> = vars.iter().map(|v| v.inner.clone()).collect();
-        
+        let meta: Vec<_> = rust_vars.iter().map(|v| (v.signal_ref, v.var_type, v.bitwidth())).collect();
+
         py.allow_threads(|| {
             self.inner.load_signals_multithreaded(&rust_vars)
                 .map(|signals| {
                     signals.into_iter()
-                        .map(|s| PySignal { inner: s })
+                        .zip(meta.into_iter())
+                        .map(|(s, (signal_ref, var_type, bitwidth))| {
+                            PySignal { inner: s, signal_ref, var_type, bitwidth, enum_def: None }
+                        })
                         .collect()
                 })
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
         })
     }
     
+    /// Load `vars` (if needed) and resample each onto the shared grid
+    /// `times`, returning one NumPy column per variable in the same order.
+    /// Lets a caller snapshot a whole signal set onto a common time base in
+    /// one call instead of looping in Python over `sample_at_times`.
+    fn sample_signals_at_times(&mut self, vars: Vec<PyVar>, times: Vec<u64>, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let rust_vars: Vec<_> = vars.iter().map(|v| v.inner.clone()).collect();
+        let signals = py.allow_threads(|| self.inner.load_signals(&rust_vars))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))?;
+
+        Ok(signals
+            .iter()
+            .map(|signal| {
+                let samples = py.allow_threads(|| signal.sample_at_times(&times));
+                numpy_export::sampled_values_to_numpy(py, &samples)
+            })
+            .collect())
+    }
+
     fn unload_signals(&self, signals: Vec<PySignal>) {
-        let refs: Vec<_> = signals.iter()
-            .map(|_| SignalRef(0)) // Would need proper tracking of signal refs
-            .collect();
+        let refs: Vec<_> = signals.iter().map(|s| s.signal_ref).collect();
         self.inner.unload_signals(&refs);
     }
+
+    /// `SignalRef`s currently held in the signal cache.
+    fn loaded_signals(&self) -> Vec<u32> {
+        self.inner.loaded_signals().into_iter().map(|r| r.0).collect()
+    }
+
+    /// Approximate byte size of every signal currently cached.
+    fn memory_usage(&self) -> usize {
+        self.inner.memory_usage()
+    }
+
+    /// Cap the signal cache at `bytes`, evicting least-recently-used
+    /// signals as needed; `None` removes the cap.
+    fn set_cache_budget(&self, bytes: Option<usize>) {
+        self.inner.set_cache_budget(bytes);
+    }
 }
 
 /// Python module definition
@@ -662,6 +834,8 @@ fn pylibfst(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyScopeIter>()?;
     m.add_class::<PySignalChangeIter>()?;
     m.add_class::<PyQueryResult>()?;
+    m.add_class::<PyEnumDef>()?;
+    m.add_class::<PyDecodedValue>()?;
     
     // Alias classes to match pywellen naming
     m.add("Waveform", m.getattr("Waveform")?)?;
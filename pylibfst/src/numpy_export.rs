@@ -0,0 +1,174 @@
+//! Bulk NumPy accessors for [`Signal`] changes and [`TimeTable`] contents.
+//!
+//! Mirrors the motivation behind [`crate::arrow_export`]: `value_at_time`
+//! and `all_changes` convert one `SignalValue` into a `PyObject` at a time,
+//! which dominates runtime on large traces. For typed (`float64`/`uint64`)
+//! columns these accessors build plain `Vec<u64>`/`Vec<f64>` and take the
+//! GIL only to hand the finished buffer to NumPy; the object-array fallback
+//! still converts each value to a `PyObject` under the GIL.
+
+use numpy::ndarray::Array1;
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::prelude::*;
+
+use crate::arrow_export::{value_column_kind, ValueColumnKind};
+use crate::signal::{Signal, SignalValue, TimeTable};
+
+/// `times` as a contiguous `uint64` ndarray, backed by the table's own
+/// storage until the copy into the NumPy-owned buffer.
+pub fn time_table_to_numpy(py: Python<'_>, table: &TimeTable) -> Py<PyArray1<u64>> {
+    table.as_slice().to_vec().into_pyarray(py).into()
+}
+
+/// NumPy column kind chosen for [`Signal::sample_at_times`] results.
+/// Unlike [`ValueColumnKind`], a gap before a signal's first change
+/// (`None`, no value carried forward yet) has to be represented too - as
+/// `NaN` for `Float64`, or by falling back to an object array of Python
+/// `None`/values otherwise, since `UInt64` has no sentinel for "missing".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SampledColumnKind {
+    Float64,
+    UInt64,
+    Object,
+}
+
+fn sampled_column_kind(samples: &[Option<SignalValue>]) -> SampledColumnKind {
+    let mut kind = None;
+    let mut has_gap = false;
+
+    for sample in samples {
+        let Some(value) = sample else {
+            has_gap = true;
+            continue;
+        };
+        let this = match value {
+            SignalValue::Real(_) => SampledColumnKind::Float64,
+            SignalValue::String(_) | SignalValue::FourValue(_) => SampledColumnKind::Object,
+            SignalValue::Binary(bits) => {
+                if bits.len() <= 64 {
+                    SampledColumnKind::UInt64
+                } else {
+                    SampledColumnKind::Object
+                }
+            }
+        };
+        kind = Some(match kind {
+            None => this,
+            Some(prev) if prev == this => prev,
+            Some(_) => SampledColumnKind::Object,
+        });
+    }
+
+    match kind {
+        Some(SampledColumnKind::Float64) => SampledColumnKind::Float64,
+        Some(SampledColumnKind::UInt64) if !has_gap => SampledColumnKind::UInt64,
+        Some(_) => SampledColumnKind::Object,
+        None => SampledColumnKind::Object,
+    }
+}
+
+/// Convert the result of [`Signal::sample_at_times`] into a single NumPy
+/// column - typed (`float64`/`uint64`) when every sample agrees, otherwise
+/// an object array with Python `None` for gaps before the first change.
+pub fn sampled_values_to_numpy(py: Python<'_>, samples: &[Option<SignalValue>]) -> PyObject {
+    match sampled_column_kind(samples) {
+        SampledColumnKind::Float64 => {
+            let values: Vec<f64> = samples
+                .iter()
+                .map(|sample| match sample {
+                    Some(SignalValue::Real(r)) => *r,
+                    Some(other) => other.to_string_repr().parse::<f64>().unwrap_or(f64::NAN),
+                    None => f64::NAN,
+                })
+                .collect();
+            values.into_pyarray(py).into_py(py)
+        }
+        SampledColumnKind::UInt64 => {
+            let values: Vec<u64> = samples
+                .iter()
+                .map(|sample| sample.as_ref().and_then(SignalValue::to_int).unwrap_or(0))
+                .collect();
+            values.into_pyarray(py).into_py(py)
+        }
+        SampledColumnKind::Object => {
+            let values: Vec<PyObject> = samples
+                .iter()
+                .map(|sample| match sample {
+                    Some(SignalValue::Binary(bits)) => {
+                        if let Some(val) = SignalValue::Binary(bits.clone()).to_int() {
+                            val.into_py(py)
+                        } else {
+                            SignalValue::Binary(bits.clone()).to_string_repr().into_py(py)
+                        }
+                    }
+                    Some(SignalValue::FourValue(s)) => s.clone().into_py(py),
+                    Some(SignalValue::Real(r)) => r.into_py(py),
+                    Some(SignalValue::String(s)) => s.clone().into_py(py),
+                    None => py.None(),
+                })
+                .collect();
+            let object_array = PyArray1::from_owned_object_array_bound(py, Array1::from(values));
+            object_array.into_py(py)
+        }
+    }
+}
+
+/// `(times, values)` for every transition in `signal`. `values` is a typed
+/// `float64`/`uint64` ndarray when every change agrees on a numeric
+/// representation (see [`value_column_kind`]), otherwise an object array of
+/// the same per-value conversions `value_at_time` already uses.
+pub fn signal_all_changes_numpy(py: Python<'_>, signal: &Signal) -> PyResult<(Py<PyArray1<u64>>, PyObject)> {
+    let kind = value_column_kind(signal);
+
+    match kind {
+        ValueColumnKind::Float64 => {
+            let (times, values) = py.allow_threads(|| {
+                let mut times = Vec::with_capacity(signal.changes.len());
+                let mut values = Vec::with_capacity(signal.changes.len());
+                for (time, value) in signal.all_changes() {
+                    times.push(time);
+                    values.push(match value {
+                        SignalValue::Real(r) => *r,
+                        other => other.to_string_repr().parse::<f64>().unwrap_or(f64::NAN),
+                    });
+                }
+                (times, values)
+            });
+            Ok((times.into_pyarray(py).into(), values.into_pyarray(py).into_py(py)))
+        }
+        ValueColumnKind::UInt64 => {
+            let (times, values) = py.allow_threads(|| {
+                let mut times = Vec::with_capacity(signal.changes.len());
+                let mut values = Vec::with_capacity(signal.changes.len());
+                for (time, value) in signal.all_changes() {
+                    times.push(time);
+                    values.push(value.to_int().unwrap_or(0));
+                }
+                (times, values)
+            });
+            Ok((times.into_pyarray(py).into(), values.into_pyarray(py).into_py(py)))
+        }
+        ValueColumnKind::Utf8 => {
+            // Mixed/wide/string signals: fall back to a NumPy object array,
+            // same per-value conversion as `PySignal::all_changes`.
+            let times: Vec<u64> = signal.all_changes().map(|(time, _)| time).collect();
+            let values: Vec<PyObject> = signal
+                .all_changes()
+                .map(|(_, value)| match value {
+                    SignalValue::Binary(bits) => {
+                        if let Some(val) = SignalValue::Binary(bits.clone()).to_int() {
+                            val.into_py(py)
+                        } else {
+                            SignalValue::Binary(bits.clone()).to_string_repr().into_py(py)
+                        }
+                    }
+                    SignalValue::FourValue(s) => s.clone().into_py(py),
+                    SignalValue::Real(r) => r.into_py(py),
+                    SignalValue::String(s) => s.clone().into_py(py),
+                })
+                .collect();
+            let object_array = PyArray1::from_owned_object_array_bound(py, Array1::from(values));
+            Ok((times.into_pyarray(py).into(), object_array.into_py(py)))
+        }
+    }
+}
@@ -135,9 +135,32 @@ impl Signal {
     pub fn all_changes_after(&self, start_time: u64) -> impl Iterator<Item = (u64, &SignalValue)> {
         let start_idx = self.changes.binary_search_by_key(&start_time, |c| c.time)
             .unwrap_or_else(|idx| idx);
-        
+
         self.changes[start_idx..].iter().map(|c| (c.time, &c.value))
     }
+
+    /// Sample this signal onto `times` (assumed sorted/non-decreasing),
+    /// carrying the last-known value forward - i.e. step interpolation.
+    ///
+    /// This is a single forward walk over both `times` and `self.changes`
+    /// together (O(changes + times)) rather than one binary search per
+    /// requested time, so resampling many signals onto a shared grid stays
+    /// linear in the data actually touched.
+    pub fn sample_at_times(&self, times: &[u64]) -> Vec<Option<SignalValue>> {
+        let mut result = Vec::with_capacity(times.len());
+        let mut change_idx = 0;
+        let mut current: Option<&SignalValue> = None;
+
+        for &t in times {
+            while change_idx < self.changes.len() && self.changes[change_idx].time <= t {
+                current = Some(&self.changes[change_idx].value);
+                change_idx += 1;
+            }
+            result.push(current.cloned());
+        }
+
+        result
+    }
     
     /// Query signal at specific time
     pub fn query_signal(&self, query_time: u64) -> QueryResult {
@@ -225,7 +248,13 @@ impl TimeTable {
     pub fn get(&self, idx: usize) -> Option<u64> {
         self.times.get(idx).copied()
     }
-    
+
+    /// The full table as a contiguous slice, for bulk export (e.g. to NumPy)
+    /// without copying through `get` one index at a time.
+    pub fn as_slice(&self) -> &[u64] {
+        &self.times
+    }
+
     pub fn len(&self) -> usize {
         self.times.len()
     }
@@ -347,11 +376,43 @@ pub fn load_signal_from_fst(
     Ok(signal)
 }
 
-/// Signal source for loading and caching signals
+/// Approximate heap bytes held by a decoded `Signal`, used to drive the
+/// cache budget: the `Signal` itself, base `SignalChange` size per change,
+/// plus whatever heap buffer its variant owns. Spare capacity in `changes`
+/// and allocator overhead are not counted.
+fn approx_signal_bytes(signal: &Signal) -> usize {
+    let mut bytes = std::mem::size_of::<Signal>();
+    for change in &signal.changes {
+        bytes += std::mem::size_of::<SignalChange>();
+        bytes += match &change.value {
+            SignalValue::Binary(bits) => bits.capacity(),
+            SignalValue::FourValue(s) => s.capacity(),
+            SignalValue::String(s) => s.capacity(),
+            SignalValue::Real(_) => 0,
+        };
+    }
+    bytes
+}
+
+/// One cached, decoded signal plus the bookkeeping the budget-based eviction
+/// needs: its approximate size and the access tick it was last touched at.
+struct CacheEntry {
+    signal: Arc<Signal>,
+    approx_bytes: usize,
+    last_used: u64,
+}
+
+/// Signal source for loading and caching signals, with an optional memory
+/// budget: once `cache_budget` is set, inserting a signal that would push
+/// total cached bytes over budget evicts the least-recently-queried signals
+/// first, so a long-running session over a huge FST doesn't just accumulate
+/// every decoded signal forever.
 pub struct SignalSource {
     reader: Arc<FstReader>,
-    signal_cache: Arc<Mutex<BTreeMap<SignalRef, Arc<Signal>>>>,
+    signal_cache: Arc<Mutex<BTreeMap<SignalRef, CacheEntry>>>,
     reader_lock: Arc<Mutex<()>>,  // Mutex to serialize FST reader access
+    cache_budget: Mutex<Option<usize>>,
+    access_clock: Mutex<u64>,
 }
 
 impl SignalSource {
@@ -360,9 +421,54 @@ impl SignalSource {
             reader,
             signal_cache: Arc::new(Mutex::new(BTreeMap::new())),
             reader_lock: Arc::new(Mutex::new(())),
+            cache_budget: Mutex::new(None),
+            access_clock: Mutex::new(0),
         }
     }
-    
+
+    fn next_tick(&self) -> u64 {
+        let mut clock = self.access_clock.lock().unwrap();
+        *clock += 1;
+        *clock
+    }
+
+    /// Evict least-recently-queried signals until the cache fits its
+    /// budget (or only one signal - the one just inserted - remains).
+    fn evict_if_over_budget(&self, cache: &mut BTreeMap<SignalRef, CacheEntry>) {
+        let Some(budget) = *self.cache_budget.lock().unwrap() else {
+            return;
+        };
+        let mut total: usize = cache.values().map(|e| e.approx_bytes).sum();
+        while total > budget && cache.len() > 1 {
+            let lru_ref = *cache
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(signal_ref, _)| signal_ref)
+                .expect("cache is non-empty");
+            if let Some(entry) = cache.remove(&lru_ref) {
+                total -= entry.approx_bytes;
+            }
+        }
+    }
+
+    /// Set (or clear, with `None`) the cache's memory budget in bytes.
+    /// Evicts immediately if the current cache already exceeds it.
+    pub fn set_cache_budget(&self, bytes: Option<usize>) {
+        *self.cache_budget.lock().unwrap() = bytes;
+        let mut cache = self.signal_cache.lock().unwrap();
+        self.evict_if_over_budget(&mut cache);
+    }
+
+    /// Approximate total bytes held by every currently-resident signal.
+    pub fn memory_usage(&self) -> usize {
+        self.signal_cache.lock().unwrap().values().map(|e| e.approx_bytes).sum()
+    }
+
+    /// `SignalRef`s currently resident in the cache.
+    pub fn loaded_signals(&self) -> Vec<SignalRef> {
+        self.signal_cache.lock().unwrap().keys().copied().collect()
+    }
+
     /// Load a single signal
     pub fn load_signal(
         &self,
@@ -373,26 +479,35 @@ impl SignalSource {
     ) -> Result<Arc<Signal>, String> {
         // Check cache first
         {
-            let cache = self.signal_cache.lock().unwrap();
-            if let Some(signal) = cache.get(&signal_ref) {
-                return Ok(signal.clone());
+            let mut cache = self.signal_cache.lock().unwrap();
+            let tick = self.next_tick();
+            if let Some(entry) = cache.get_mut(&signal_ref) {
+                entry.last_used = tick;
+                return Ok(entry.signal.clone());
             }
         }
-        
+
         // Load signal from FST with mutex protection
         // The FST C library is not thread-safe for concurrent block iteration
         let signal = {
             let _lock = self.reader_lock.lock().unwrap();
             load_signal_from_fst(&self.reader, handle, is_real, is_string)?
         };
+        let approx_bytes = approx_signal_bytes(&signal);
         let signal_arc = Arc::new(signal);
-        
+
         // Store in cache
         {
             let mut cache = self.signal_cache.lock().unwrap();
-            cache.insert(signal_ref, signal_arc.clone());
+            let tick = self.next_tick();
+            cache.insert(signal_ref, CacheEntry {
+                signal: signal_arc.clone(),
+                approx_bytes,
+                last_used: tick,
+            });
+            self.evict_if_over_budget(&mut cache);
         }
-        
+
         Ok(signal_arc)
     }
     
@@ -0,0 +1,106 @@
+//! VarType-aware decoding of raw [`SignalValue`]s, plus an opt-in enum
+//! symbol lookup. `PySignal::value_at_time` normally hands back whatever
+//! `SignalValue` converts to by default - unsigned bit patterns, strings,
+//! floats. This module adds the opinionated layer on top: signed
+//! twos-complement integers for HDL integer types, booleans for 1-bit
+//! signals, and enum symbol names when the caller has registered a table -
+//! without touching the raw path those other accessors still use.
+
+use std::collections::HashMap;
+
+use crate::hierarchy::VarType;
+use crate::signal::SignalValue;
+
+/// Raw-bit-string -> symbolic name table a caller can attach to a
+/// `PySignal` before requesting `value_at_time(time, decoded=True)`.
+#[derive(Debug, Clone, Default)]
+pub struct EnumDef {
+    symbols: HashMap<String, String>,
+}
+
+impl EnumDef {
+    pub fn new(mapping: Vec<(String, String)>) -> Self {
+        EnumDef {
+            symbols: mapping.into_iter().collect(),
+        }
+    }
+
+    fn symbol_for(&self, raw_bits: &str) -> Option<&str> {
+        self.symbols.get(raw_bits).map(String::as_str)
+    }
+}
+
+/// Tagged decode of one [`SignalValue`], keyed on a stable discriminant so
+/// callers can branch on the kind of value they got instead of relying on
+/// Python `isinstance` checks.
+#[derive(Debug, Clone)]
+pub enum ConvertedValue {
+    /// Four-value content (`x`/`z`) or an oversized bit vector that can't
+    /// be interpreted as a clean number - the bit string as-is.
+    Raw(String),
+    Signed(i64),
+    Unsigned(u64),
+    Bool(bool),
+    Float(f64),
+    Text(String),
+    /// Symbolic name resolved via a registered `EnumDef`.
+    Enum(String),
+}
+
+/// `Integer`/`Int`/`ShortInt`/`LongInt`/`Byte` are the HDL types whose
+/// values should be interpreted as twos-complement signed integers rather
+/// than raw unsigned bit patterns.
+fn is_signed_int_type(var_type: VarType) -> bool {
+    matches!(
+        var_type,
+        VarType::Integer | VarType::Int | VarType::ShortInt | VarType::LongInt | VarType::Byte
+    )
+}
+
+/// Sign-extend the low `width` bits of `value` (`width` clamped to 64).
+fn sign_extend(value: u64, width: u32) -> i64 {
+    let width = width.clamp(1, 64);
+    if width == 64 {
+        return value as i64;
+    }
+    let shift = 64 - width;
+    ((value << shift) as i64) >> shift
+}
+
+/// Decode `value` using `var_type`/`bitwidth` for signed/bool
+/// interpretation. `enum_def`, when given, takes priority over numeric
+/// interpretation for binary values.
+pub fn decode_value(
+    value: &SignalValue,
+    var_type: VarType,
+    bitwidth: Option<u32>,
+    enum_def: Option<&EnumDef>,
+) -> ConvertedValue {
+    match value {
+        SignalValue::Real(r) => ConvertedValue::Float(*r),
+        SignalValue::String(s) => ConvertedValue::Text(s.clone()),
+        SignalValue::FourValue(s) => ConvertedValue::Raw(s.clone()),
+        SignalValue::Binary(bits) => {
+            let raw_bits = SignalValue::Binary(bits.clone()).to_string_repr();
+
+            if let Some(symbol) = enum_def.and_then(|def| def.symbol_for(&raw_bits)) {
+                return ConvertedValue::Enum(symbol.to_string());
+            }
+
+            let Some(unsigned) = SignalValue::Binary(bits.clone()).to_int() else {
+                return ConvertedValue::Raw(raw_bits);
+            };
+
+            if bitwidth == Some(1) {
+                return ConvertedValue::Bool(unsigned != 0);
+            }
+
+            if is_signed_int_type(var_type) {
+                let width = bitwidth.unwrap_or(bits.len() as u32);
+                ConvertedValue::Signed(sign_extend(unsigned, width))
+            } else {
+                ConvertedValue::Unsigned(unsigned)
+            }
+        }
+    }
+}